@@ -3,7 +3,11 @@ use std::collections::HashMap;
 use log::debug;
 
 use crate::core::{
-    admin::{ConsumerGroupInfo, PartitionOffset, Topic, TopicInfo},
+    admin::{
+        AclBinding, AclBindingFilter, ClusterInfo, ConsumerGroupInfo, ConsumerGroupLag, ConsumerGroupOffsets,
+        CreateTopicOptions, DeleteRecordsConfiguration, DeletedRecords, OffsetReset, OffsetResetStrategy,
+        OffsetsFileFormat, PartitionOffset, Topic, TopicInfo,
+    },
     consumer::types::ConsumerOffsetConfiguration,
 };
 
@@ -12,6 +16,56 @@ use super::{
     AppState,
 };
 
+#[tauri::command]
+pub async fn describe_acls(
+    cluster_id: &str,
+    filter: Option<AclBindingFilter>,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<AclBinding>> {
+    debug!("Describe ACLs matching {:?}", filter);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .describe_acls(&filter.unwrap_or_default())
+        .await?)
+}
+
+#[tauri::command]
+pub async fn create_acls(cluster_id: &str, acls: Vec<AclBinding>, state: tauri::State<'_, AppState>) -> ApiResult<()> {
+    debug!("Create ACLs {:?}", acls);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster.kafka_admin_client.create_acls(&acls).await?)
+}
+
+#[tauri::command]
+pub async fn delete_acls(
+    cluster_id: &str,
+    filters: Vec<AclBindingFilter>,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<AclBinding>> {
+    debug!("Delete ACLs matching {:?}", filters);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster.kafka_admin_client.delete_acls(&filters).await?)
+}
+
+#[tauri::command]
+pub async fn describe_cluster(cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<ClusterInfo> {
+    debug!("Describe cluster {}", cluster_id);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster.kafka_admin_client.describe_cluster().await?)
+}
+
+#[tauri::command]
+pub async fn get_broker_configuration(
+    cluster_id: &str,
+    broker_id: i32,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<HashMap<String, Option<String>>> {
+    debug!("Retrieve the configurations of broker {}", broker_id);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster.kafka_admin_client.get_broker_configuration(broker_id).await?)
+}
+
 #[tauri::command]
 pub async fn list_topics(cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<Vec<Topic>> {
     debug!("Retrieve the list of topics");
@@ -37,6 +91,7 @@ pub async fn delete_topic(cluster_id: &str, topic_name: &str, state: tauri::Stat
     Ok(cluster.kafka_admin_client.delete_topic(topic_name).await?)
 }
 
+/// Create a new topic. The `cleanup.policy` in the configurations takes precedence over `compacted`
 #[tauri::command]
 pub async fn create_topic(
     cluster_id: &str,
@@ -44,13 +99,81 @@ pub async fn create_topic(
     partitions: i32,
     isr: i32,
     compacted: bool,
+    options: Option<CreateTopicOptions>,
     state: tauri::State<'_, AppState>,
 ) -> ApiResult<()> {
     debug!("Create new topic");
     let cluster = state.get_cluster(cluster_id).await?;
+    let mut options = options.unwrap_or_default();
+    options
+        .configurations
+        .entry("cleanup.policy".into())
+        .or_insert_with(|| if compacted { "compact" } else { "delete" }.into());
+    Ok(cluster
+        .kafka_admin_client
+        .create_topic_with_options(topic_name, partitions, isr, &options)
+        .await?)
+}
+
+/// Update the topic configurations. A None value resets the configuration to the default
+#[tauri::command]
+pub async fn alter_topic_configuration(
+    cluster_id: &str,
+    topic_name: &str,
+    configurations: HashMap<String, Option<String>>,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<()> {
+    debug!("Alter the configurations of topic {}", topic_name);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .alter_topic_configuration(topic_name, &configurations)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn create_partitions(
+    cluster_id: &str,
+    topic_name: &str,
+    partition_count: usize,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<()> {
+    debug!("Increase the partitions of topic {} to {}", topic_name, partition_count);
+    let cluster = state.get_cluster(cluster_id).await?;
     Ok(cluster
         .kafka_admin_client
-        .create_topic(topic_name, partitions, isr, compacted)
+        .create_partitions(topic_name, partition_count)
+        .await?)
+}
+
+/// Retrieve the number of records that would be deleted from each partition, without deleting them
+#[tauri::command]
+pub async fn preview_delete_records(
+    cluster_id: &str,
+    topic_name: &str,
+    configuration: DeleteRecordsConfiguration,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<DeletedRecords>> {
+    debug!("Preview delete records from topic {}", topic_name);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .preview_delete_records(topic_name, &configuration)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn delete_records(
+    cluster_id: &str,
+    topic_name: &str,
+    configuration: DeleteRecordsConfiguration,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<DeletedRecords>> {
+    debug!("Delete records from topic {} {:?}", topic_name, configuration);
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .delete_records(topic_name, &configuration)
         .await?)
 }
 
@@ -76,6 +199,21 @@ pub async fn describe_consumer_group(
         .await?)
 }
 
+#[tauri::command]
+pub async fn get_consumer_group_lag(
+    cluster_id: &str,
+    consumer_group_name: &str,
+    ignore_cache: Option<bool>,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<ConsumerGroupLag> {
+    debug!("Get consumer group lag");
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .get_consumer_group_lag(consumer_group_name, ignore_cache.unwrap_or(false))
+        .await?)
+}
+
 #[tauri::command]
 pub async fn get_consumer_group_state(
     cluster_id: &str,
@@ -113,6 +251,73 @@ pub async fn set_consumer_group(
         .set_consumer_group(consumer_group_name, &topics, &offset_config)?)
 }
 
+#[tauri::command]
+pub async fn reset_consumer_group_offsets(
+    cluster_id: &str,
+    consumer_group_name: &str,
+    topic_name: &str,
+    strategy: OffsetResetStrategy,
+    partitions: Option<Vec<i32>>,
+    dry_run: bool,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<OffsetReset>> {
+    debug!(
+        "Reset offsets of consumer group {} on topic {} to {:?}, dry run: {}",
+        consumer_group_name, topic_name, strategy, dry_run
+    );
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .reset_consumer_group_offsets(
+            consumer_group_name,
+            topic_name,
+            &strategy,
+            partitions.as_deref(),
+            dry_run,
+        )
+        .await?)
+}
+
+#[tauri::command]
+pub async fn export_consumer_group_offsets(
+    cluster_id: &str,
+    consumer_group_name: &str,
+    output_path: &str,
+    format: OffsetsFileFormat,
+    overwrite: bool,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<ConsumerGroupOffsets> {
+    debug!(
+        "Export offsets of consumer group {} to {}",
+        consumer_group_name, output_path
+    );
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .export_consumer_group_offsets(consumer_group_name, output_path, format, overwrite)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn import_consumer_group_offsets(
+    cluster_id: &str,
+    consumer_group_name: &str,
+    input_path: &str,
+    format: OffsetsFileFormat,
+    dry_run: bool,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<OffsetReset>> {
+    debug!(
+        "Import offsets of consumer group {} from {}, dry run: {}",
+        consumer_group_name, input_path, dry_run
+    );
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster
+        .kafka_admin_client
+        .import_consumer_group_offsets(consumer_group_name, input_path, format, dry_run)
+        .await?)
+}
+
 #[tauri::command]
 pub async fn get_last_offsets(
     cluster_id: &str,
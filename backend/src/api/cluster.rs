@@ -1,5 +1,5 @@
 use log::{debug, trace};
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 use tokio::sync::RwLock;
 
 use crate::core::{
@@ -7,32 +7,42 @@ use crate::core::{
     configuration::InsulatorConfig,
     consumer::{ConsumerError, KafkaConsumer},
     error_callback::ErrorCallback,
-    parser::Parser,
+    kafka_connect::KafkaConnectClient,
+    parser::{Parser, SubjectNameResolver},
     producer::KafkaProducer,
-    record_store::SqliteStore,
-    record_store::TopicStore,
+    protobuf::LocalProtoSchemas,
+    record_store::{RecordStore, SqliteStore, TopicStore},
     schema_registry::CachedSchemaRegistry,
 };
 
 use super::error::{ApiError, ApiResult};
 
-type TopicName = String;
+type ConsumerName = String;
 
 pub struct Cluster {
     pub cluster_id: String,
     pub config: InsulatorConfig,
     pub schema_registry_client: Option<Arc<CachedSchemaRegistry>>,
+    pub kafka_connect_client: Option<Arc<KafkaConnectClient>>,
     pub kafka_admin_client: Arc<KafkaAdmin>,
     pub kafka_producer: Arc<KafkaProducer>,
     pub parser: Arc<Parser>,
     pub store: Arc<SqliteStore>,
-    active_kafka_consumers: Arc<RwLock<HashMap<TopicName, Arc<KafkaConsumer>>>>,
+    active_kafka_consumers: Arc<RwLock<HashMap<ConsumerName, Arc<KafkaConsumer>>>>,
     error_callback: ErrorCallback<ApiError>,
 }
 
 impl Cluster {
     pub fn new(cluster_id: &str, config: &InsulatorConfig, error_callback: ErrorCallback<ApiError>) -> ApiResult<Self> {
         let cluster_config = config.get_cluster_config(cluster_id)?;
+        let local_proto_schemas = match &cluster_config.protobuf {
+            Some(protobuf_config) => Some(LocalProtoSchemas::load(protobuf_config)?),
+            None => None,
+        };
+        let subject_name_resolver = match &cluster_config.subject_name_strategy {
+            Some(strategy_config) => SubjectNameResolver::new(strategy_config)?,
+            None => SubjectNameResolver::default(),
+        };
         let (schema_registry_client, parser) = {
             if let Some(s_config) = &cluster_config.schema_registry {
                 let ptr = Arc::new(CachedSchemaRegistry::new(
@@ -40,33 +50,61 @@ impl Cluster {
                     s_config.username.as_deref(),
                     s_config.password.as_deref(),
                 ));
-                (Some(ptr.clone()), Arc::new(Parser::new(Some(ptr))))
+                (
+                    Some(ptr.clone()),
+                    Arc::new(Parser::new(Some(ptr), local_proto_schemas, subject_name_resolver)),
+                )
             } else {
-                (None, Arc::new(Parser::new(None)))
+                (
+                    None,
+                    Arc::new(Parser::new(None, local_proto_schemas, subject_name_resolver)),
+                )
             }
         };
+        let kafka_connect_client = cluster_config.kafka_connect.as_ref().map(|k_config| {
+            Arc::new(KafkaConnectClient::new(
+                k_config.endpoint.as_str(),
+                k_config.username.as_deref(),
+                k_config.password.as_deref(),
+            ))
+        });
         Ok(Cluster {
             cluster_id: cluster_id.to_string(),
             schema_registry_client,
+            kafka_connect_client,
             active_kafka_consumers: Arc::new(RwLock::new(HashMap::new())),
             kafka_admin_client: Arc::new(KafkaAdmin::new(&cluster_config, config.get_kafka_tmo())?),
-            kafka_producer: Arc::new(KafkaProducer::new(&cluster_config, parser.clone())),
+            kafka_producer: Arc::new(KafkaProducer::new(
+                &cluster_config,
+                parser.clone(),
+                config.get_kafka_tmo(),
+            )),
             parser,
-            store: Arc::new(SqliteStore::new(config.get_sql_tmo())),
+            store: Arc::new(match &cluster_config.record_store {
+                Some(store_config) => {
+                    let db_path: PathBuf = [store_config.location.as_str(), &format!("{cluster_id}.db")]
+                        .iter()
+                        .collect();
+                    SqliteStore::open(&db_path, store_config.max_size_mb, config.get_sql_tmo())?
+                }
+                None => SqliteStore::new(config.get_sql_tmo()),
+            }),
             error_callback,
             config: config.clone(),
         })
     }
 
-    pub async fn get_consumer(&self, topic_name: &str) -> Arc<KafkaConsumer> {
+    /// Retrieve the consumer session with the specified name, creating it if not exists.
+    /// The name can be a topic, a comma separated list of topics or a regex starting with `^`
+    pub async fn get_consumer(&self, consumer_name: &str) -> Arc<KafkaConsumer> {
         {
-            if let Some(consumer) = self.active_kafka_consumers.read().await.get(topic_name) {
-                trace!("Consumer for {} found in cache", topic_name);
+            if let Some(consumer) = self.active_kafka_consumers.read().await.get(consumer_name) {
+                trace!("Consumer for {} found in cache", consumer_name);
                 return consumer.clone();
             }
         }
         {
-            debug!("Create consumer for topic {}", topic_name);
+            debug!("Create consumer {}", consumer_name);
             let cluster_config = self
                 .config
                 .get_cluster_config(&self.cluster_id)
@@ -74,13 +112,13 @@ impl Cluster {
 
             // create a new table for the consumer
             let topic_store =
-                TopicStore::from_record_store(self.store.clone(), self.parser.clone(), &self.cluster_id, topic_name);
+                TopicStore::from_record_store(self.store.clone(), self.parser.clone(), &self.cluster_id, consumer_name);
             // build the consumer callback
             let error_cb = self.error_callback.clone();
             let consumer_callback: ErrorCallback<ConsumerError> = Arc::new(move |err| (*error_cb)(err.into()));
             let consumer = Arc::new(KafkaConsumer::new(
                 &cluster_config,
-                topic_name,
+                consumer_name,
                 topic_store,
                 consumer_callback,
                 self.config.get_kafka_tmo(),
@@ -88,13 +126,22 @@ impl Cluster {
             self.active_kafka_consumers
                 .write()
                 .await
-                .insert(topic_name.to_string(), consumer.clone());
+                .insert(consumer_name.to_string(), consumer.clone());
             consumer
         }
     }
 
-    pub async fn get_topic_store(&self, topic_name: &str) -> Arc<TopicStore> {
-        let consumer = self.get_consumer(topic_name).await;
+    pub async fn get_topic_store(&self, consumer_name: &str) -> Arc<TopicStore> {
+        let consumer = self.get_consumer(consumer_name).await;
         consumer.topic_store.clone()
     }
+
+    /// Stop the consumer with the specified name, if any, and delete all the records stored
+    pub async fn delete_topic_store(&self, consumer_name: &str) -> ApiResult<()> {
+        debug!("Delete the records stored for {}", consumer_name);
+        if let Some(consumer) = self.active_kafka_consumers.write().await.remove(consumer_name) {
+            consumer.stop().await?;
+        }
+        Ok(self.store.destroy(&self.cluster_id, consumer_name)?)
+    }
 }
@@ -2,11 +2,13 @@ use log::debug;
 
 use crate::core::{
     consumer::{types::ConsumerState, ConsumerConfiguration},
-    record_store::types::ExportOptions,
+    record_store::types::{ExportOptions, StoredTopic},
 };
 
 use super::{error::ApiResult, types::GetPageResponse, AppState};
 
+/// The topic can be a topic name, a comma separated list of topics or a regex starting with `^`.
+/// All the records consumed are stored in the same table, with the source topic in the `topic` column.
 #[tauri::command]
 pub async fn start_consumer(
     cluster_id: &str,
@@ -69,3 +71,16 @@ pub async fn export_records(
     let store = state.get_cluster(cluster_id).await?.get_topic_store(topic).await;
     Ok(store.export_records(&options)?)
 }
+
+/// List the topics with records stored for the cluster, including the ones consumed in a previous session
+/// if the cluster uses an on disk record store
+#[tauri::command]
+pub async fn list_stored_topics(cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<Vec<StoredTopic>> {
+    let cluster = state.get_cluster(cluster_id).await?;
+    Ok(cluster.store.list_topic_tables(cluster_id)?)
+}
+
+#[tauri::command]
+pub async fn delete_stored_topic(cluster_id: &str, topic: &str, state: tauri::State<'_, AppState>) -> ApiResult<()> {
+    state.get_cluster(cluster_id).await?.delete_topic_store(topic).await
+}
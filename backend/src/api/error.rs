@@ -1,6 +1,7 @@
 use crate::core::{
-    admin::AdminError, avro::AvroError, configuration::ConfigError, consumer::ConsumerError, producer::ProducerError,
-    record_store::StoreError, schema_registry::SchemaRegistryError,
+    admin::AdminError, avro::AvroError, configuration::ConfigError, consumer::ConsumerError,
+    json_schema::JsonSchemaError, kafka_connect::KafkaConnectError, parser::ParserError, producer::ProducerError,
+    protobuf::ProtobufError, record_store::StoreError, schema_registry::SchemaRegistryError,
 };
 use serde::{Deserialize, Serialize};
 
@@ -48,6 +49,33 @@ impl From<SchemaRegistryError> for ApiError {
     }
 }
 
+impl From<KafkaConnectError> for ApiError {
+    fn from(err: KafkaConnectError) -> Self {
+        match err {
+            KafkaConnectError::ConnectorNotFound(name) => ApiError {
+                error_type: "Kafka Connect error: Connector not found".into(),
+                message: format!("Connector {name} not found"),
+            },
+            KafkaConnectError::RebalanceInProgress => ApiError {
+                error_type: "Kafka Connect error: Rebalance in progress".into(),
+                message: "The Connect cluster is rebalancing, try again in a few seconds".into(),
+            },
+            KafkaConnectError::InvalidConfiguration(message) => ApiError {
+                error_type: "Kafka Connect error: Invalid configuration".into(),
+                message,
+            },
+            KafkaConnectError::HttpClient(message) => ApiError {
+                error_type: "Kafka Connect error: HTTPClient".into(),
+                message,
+            },
+            KafkaConnectError::InvalidUrl(message) => ApiError {
+                error_type: "Kafka Connect error: Invalid URL".into(),
+                message,
+            },
+        }
+    }
+}
+
 impl From<StoreError> for ApiError {
     fn from(value: StoreError) -> Self {
         match value {
@@ -79,6 +107,10 @@ impl From<ConsumerError> for ApiError {
                 error_type: "Consumer error".into(),
                 message,
             },
+            ConsumerError::InvalidSubscription(message) => ApiError {
+                error_type: "Consumer error: Invalid subscription".into(),
+                message,
+            },
         }
     }
 }
@@ -90,11 +122,27 @@ impl From<AdminError> for ApiError {
                 error_type: "Admin client".into(),
                 message: format!("Topic {topic_name} not found."),
             },
+            AdminError::ConsumerGroupNotFound(name) => ApiError {
+                error_type: "Admin client".into(),
+                message: format!("Consumer group {name} not found."),
+            },
             AdminError::RDKafka(message) => ApiError {
                 error_type: "RDKafkaLib error".into(),
                 message,
             },
             AdminError::ConsumerError(consumer_error) => consumer_error.into(),
+            AdminError::InvalidConfiguration(message) => ApiError {
+                error_type: "Admin client: Invalid configuration".into(),
+                message,
+            },
+            AdminError::IO(message) => ApiError {
+                error_type: "Admin client: IO error".into(),
+                message,
+            },
+            AdminError::InvalidOffsetsFile(message) => ApiError {
+                error_type: "Admin client: Invalid offsets file".into(),
+                message,
+            },
         }
     }
 }
@@ -138,10 +186,30 @@ impl From<ProducerError> for ApiError {
                 message,
             },
             ProducerError::AvroParse(avro_error) => avro_error.into(),
+            ProducerError::MissingProtobufConfiguration => Self {
+                error_type: "Missing protobuf configuration".into(),
+                message: "Unable to parse the record to protobuf".into(),
+            },
+            ProducerError::ProtobufParse(protobuf_error) => protobuf_error.into(),
+            ProducerError::MissingJsonSchemaConfiguration => Self {
+                error_type: "Missing schema registry configuration".into(),
+                message: "Unable to parse the record to json schema".into(),
+            },
+            ProducerError::JsonSchemaParse(json_schema_error) => json_schema_error.into(),
+            ProducerError::SubjectName(message) => Self {
+                error_type: "Subject name strategy error".into(),
+                message,
+            },
         }
     }
 }
 
+impl From<ParserError> for ApiError {
+    fn from(value: ParserError) -> Self {
+        ProducerError::from(value).into()
+    }
+}
+
 impl From<AvroError> for ApiError {
     fn from(value: AvroError) -> Self {
         match value {
@@ -188,3 +256,65 @@ impl From<AvroError> for ApiError {
         }
     }
 }
+
+impl From<ProtobufError> for ApiError {
+    fn from(value: ProtobufError) -> Self {
+        match value {
+            ProtobufError::SchemaProvider(message, _) => ApiError {
+                error_type: "Protobuf error: SchemaProvider".into(),
+                message,
+            },
+            ProtobufError::SchemaCompilation(message) => ApiError {
+                error_type: "Protobuf error: SchemaCompilation".into(),
+                message,
+            },
+            ProtobufError::MissingSchema(message) => ApiError {
+                error_type: "Protobuf error: MissingSchema".into(),
+                message,
+            },
+            ProtobufError::MessageNotFound(message) => ApiError {
+                error_type: "Protobuf error: MessageNotFound".into(),
+                message,
+            },
+            ProtobufError::InvalidProtobufHeader(message) => ApiError {
+                error_type: "Protobuf error: InvalidProtobufHeader".into(),
+                message,
+            },
+            ProtobufError::ParseProtobufValue(message) => ApiError {
+                error_type: "Protobuf error: ParseProtobufValue".into(),
+                message,
+            },
+            ProtobufError::ParseJsonValue(message) => ApiError {
+                error_type: "Protobuf error: ParseJsonValue".into(),
+                message,
+            },
+        }
+    }
+}
+
+impl From<JsonSchemaError> for ApiError {
+    fn from(value: JsonSchemaError) -> Self {
+        match value {
+            JsonSchemaError::SchemaProvider(message, _) => ApiError {
+                error_type: "Json schema error: SchemaProvider".into(),
+                message,
+            },
+            JsonSchemaError::SchemaCompilation(message) => ApiError {
+                error_type: "Json schema error: SchemaCompilation".into(),
+                message,
+            },
+            JsonSchemaError::InvalidJsonSchemaHeader(message) => ApiError {
+                error_type: "Json schema error: InvalidJsonSchemaHeader".into(),
+                message,
+            },
+            JsonSchemaError::ParseJsonValue(message) => ApiError {
+                error_type: "Json schema error: ParseJsonValue".into(),
+                message,
+            },
+            JsonSchemaError::Validation(message) => ApiError {
+                error_type: "Json schema error: Validation".into(),
+                message,
+            },
+        }
+    }
+}
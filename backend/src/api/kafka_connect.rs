@@ -0,0 +1,104 @@
+use std::{collections::HashMap, sync::Arc};
+
+use log::debug;
+
+use crate::core::kafka_connect::{ConnectorStatus, KafkaConnectClient};
+
+use super::{
+    error::{ApiError, ApiResult},
+    AppState,
+};
+
+async fn get_client(cluster_id: &str, state: &tauri::State<'_, AppState>) -> ApiResult<Arc<KafkaConnectClient>> {
+    state.get_kafka_connect_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing Kafka Connect configuration".into(),
+    })
+}
+
+#[tauri::command]
+pub async fn list_connectors(cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<Vec<ConnectorStatus>> {
+    debug!("List Kafka Connect connectors");
+    Ok(get_client(cluster_id, &state).await?.list_connectors().await?)
+}
+
+#[tauri::command]
+pub async fn get_connector_status(
+    cluster_id: &str,
+    name: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<ConnectorStatus> {
+    debug!("Get connector {} status", name);
+    Ok(get_client(cluster_id, &state).await?.get_connector_status(name).await?)
+}
+
+#[tauri::command]
+pub async fn get_connector_config(
+    cluster_id: &str,
+    name: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<HashMap<String, String>> {
+    debug!("Get connector {} configuration", name);
+    Ok(get_client(cluster_id, &state).await?.get_connector_config(name).await?)
+}
+
+#[tauri::command]
+pub async fn update_connector_config(
+    cluster_id: &str,
+    name: &str,
+    config: HashMap<String, String>,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<HashMap<String, String>> {
+    debug!("Update connector {} configuration", name);
+    Ok(get_client(cluster_id, &state)
+        .await?
+        .update_connector_config(name, &config)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn pause_connector(cluster_id: &str, name: &str, state: tauri::State<'_, AppState>) -> ApiResult<()> {
+    debug!("Pause connector {}", name);
+    Ok(get_client(cluster_id, &state).await?.pause_connector(name).await?)
+}
+
+#[tauri::command]
+pub async fn resume_connector(cluster_id: &str, name: &str, state: tauri::State<'_, AppState>) -> ApiResult<()> {
+    debug!("Resume connector {}", name);
+    Ok(get_client(cluster_id, &state).await?.resume_connector(name).await?)
+}
+
+#[tauri::command]
+pub async fn restart_connector(
+    cluster_id: &str,
+    name: &str,
+    include_tasks: bool,
+    only_failed: bool,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<()> {
+    debug!("Restart connector {}", name);
+    Ok(get_client(cluster_id, &state)
+        .await?
+        .restart_connector(name, include_tasks, only_failed)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn restart_connector_task(
+    cluster_id: &str,
+    name: &str,
+    task_id: i32,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<()> {
+    debug!("Restart task {} of connector {}", task_id, name);
+    Ok(get_client(cluster_id, &state)
+        .await?
+        .restart_task(name, task_id)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn delete_connector(cluster_id: &str, name: &str, state: tauri::State<'_, AppState>) -> ApiResult<()> {
+    debug!("Delete connector {}", name);
+    Ok(get_client(cluster_id, &state).await?.delete_connector(name).await?)
+}
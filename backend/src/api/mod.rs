@@ -3,6 +3,7 @@ mod cluster;
 pub mod configuration;
 pub mod consumer;
 mod error;
+pub mod kafka_connect;
 mod notification;
 pub mod producer;
 pub mod schema_registry;
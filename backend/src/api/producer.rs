@@ -1,4 +1,7 @@
-use crate::core::types::ParserMode;
+use crate::core::{
+    producer::{DeliveryReport, ProduceOptions},
+    types::ParserMode,
+};
 
 use super::{error::ApiResult, AppState};
 
@@ -9,8 +12,12 @@ pub async fn produce_record(
     key: &str,
     value: Option<&str>, // None would be a tombstone
     mode: ParserMode,
+    options: Option<ProduceOptions>,
     state: tauri::State<'_, AppState>,
-) -> ApiResult<()> {
+) -> ApiResult<DeliveryReport> {
     let cluster = state.get_cluster(cluster_id).await?;
-    Ok(cluster.kafka_producer.produce(topic, key, value, mode).await?)
+    Ok(cluster
+        .kafka_producer
+        .produce(topic, key, value, mode, &options.unwrap_or_default())
+        .await?)
 }
@@ -1,4 +1,7 @@
-use crate::core::schema_registry::Subject;
+use crate::core::{
+    avro::SchemaChange,
+    schema_registry::{CompatibilityCheck, SchemaReference, SchemaRegistryMode, SchemaType, Subject},
+};
 use log::debug;
 
 use super::{
@@ -27,19 +30,53 @@ pub async fn get_subject(subject_name: &str, cluster_id: &str, state: tauri::Sta
 }
 
 #[tauri::command]
-pub async fn delete_subject(subject_name: &str, cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<()> {
+pub async fn list_deleted_subjects(cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<Vec<String>> {
+    debug!("List soft deleted schema registry subjects");
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.list_deleted_subjects().await?)
+}
+
+#[tauri::command]
+pub async fn list_deleted_subject_versions(
+    subject_name: &str,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<i32>> {
+    debug!("List soft deleted versions of subject {}", subject_name);
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.list_deleted_versions(subject_name).await?)
+}
+
+/// The delete is soft unless `permanent` is set
+#[tauri::command]
+pub async fn delete_subject(
+    subject_name: &str,
+    permanent: Option<bool>,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<()> {
     debug!("Deleting subject {}", subject_name);
     let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
         error_type: "Configuration error".into(),
         message: "Missing schema registry configuration".into(),
     })?;
-    Ok(client.delete_subject(subject_name).await?)
+    Ok(client
+        .delete_subject(subject_name, permanent.unwrap_or_default())
+        .await?)
 }
 
+/// The delete is soft unless `permanent` is set
 #[tauri::command]
 pub async fn delete_subject_version(
     subject_name: &str,
     version: i32,
+    permanent: Option<bool>,
     cluster_id: &str,
     state: tauri::State<'_, AppState>,
 ) -> ApiResult<()> {
@@ -48,13 +85,18 @@ pub async fn delete_subject_version(
         error_type: "Configuration error".into(),
         message: "Missing schema registry configuration".into(),
     })?;
-    Ok(client.delete_version(subject_name, version).await?)
+    Ok(client
+        .delete_version(subject_name, version, permanent.unwrap_or_default())
+        .await?)
 }
 
+/// The schema type defaults to avro if not specified
 #[tauri::command]
 pub async fn post_schema(
     subject_name: &str,
     schema: &str,
+    schema_type: Option<SchemaType>,
+    references: Option<Vec<SchemaReference>>,
     cluster_id: &str,
     state: tauri::State<'_, AppState>,
 ) -> ApiResult<()> {
@@ -63,7 +105,57 @@ pub async fn post_schema(
         error_type: "Configuration error".into(),
         message: "Missing schema registry configuration".into(),
     })?;
-    Ok(client.post_schema(subject_name, schema).await?)
+    Ok(client
+        .post_schema(
+            subject_name,
+            schema,
+            schema_type.unwrap_or_default(),
+            &references.unwrap_or_default(),
+        )
+        .await?)
+}
+
+/// Check the schema against a version of the subject, the latest if not specified
+#[tauri::command]
+pub async fn check_schema_compatibility(
+    subject_name: &str,
+    schema: &str,
+    schema_type: Option<SchemaType>,
+    references: Option<Vec<SchemaReference>>,
+    version: Option<i32>,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<CompatibilityCheck> {
+    debug!("Check schema compatibility for subject {}", subject_name);
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client
+        .check_compatibility(
+            subject_name,
+            schema,
+            schema_type.unwrap_or_default(),
+            &references.unwrap_or_default(),
+            version,
+        )
+        .await?)
+}
+
+#[tauri::command]
+pub async fn diff_schema_versions(
+    subject_name: &str,
+    from_version: i32,
+    to_version: i32,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<Vec<SchemaChange>> {
+    debug!("Diff subject {subject_name} versions {from_version} and {to_version}");
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.diff_versions(subject_name, from_version, to_version).await?)
 }
 
 #[tauri::command]
@@ -78,5 +170,62 @@ pub async fn set_compatibility_level(
         error_type: "Configuration error".into(),
         message: "Missing schema registry configuration".into(),
     })?;
-    Ok(client.set_compatibility_level(subject_name, compatibility).await?)
+    Ok(client
+        .set_compatibility_level(Some(subject_name), compatibility)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn get_global_compatibility_level(cluster_id: &str, state: tauri::State<'_, AppState>) -> ApiResult<String> {
+    debug!("Retrieve the global compatibility level");
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.get_compatibility_level(None).await?)
+}
+
+#[tauri::command]
+pub async fn set_global_compatibility_level(
+    compatibility: &str,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<String> {
+    debug!("Set the global compatibility level to {compatibility}");
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.set_compatibility_level(None, compatibility).await?)
+}
+
+/// Retrieve the mode of the subject, the global one if the subject is not specified
+#[tauri::command]
+pub async fn get_schema_registry_mode(
+    subject_name: Option<&str>,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<SchemaRegistryMode> {
+    debug!("Retrieve the schema registry mode for {:?}", subject_name);
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.get_mode(subject_name).await?)
+}
+
+/// Set the mode of the subject, the global one if the subject is not specified
+#[tauri::command]
+pub async fn set_schema_registry_mode(
+    subject_name: Option<&str>,
+    mode: SchemaRegistryMode,
+    cluster_id: &str,
+    state: tauri::State<'_, AppState>,
+) -> ApiResult<SchemaRegistryMode> {
+    debug!("Set the schema registry mode for {:?} to {:?}", subject_name, mode);
+    let client = state.get_schema_reg_client(cluster_id).await?.ok_or(ApiError {
+        error_type: "Configuration error".into(),
+        message: "Missing schema registry configuration".into(),
+    })?;
+    Ok(client.set_mode(subject_name, mode).await?)
 }
@@ -5,7 +5,8 @@ use tauri::Manager;
 use tokio::sync::RwLock;
 
 use crate::core::{
-    configuration::ConfigurationProvider, error_callback::ErrorCallback, schema_registry::CachedSchemaRegistry,
+    configuration::ConfigurationProvider, error_callback::ErrorCallback, kafka_connect::KafkaConnectClient,
+    schema_registry::CachedSchemaRegistry,
 };
 
 use super::{
@@ -52,6 +53,11 @@ impl AppState {
         Ok(cluster.schema_registry_client.as_ref().cloned())
     }
 
+    pub async fn get_kafka_connect_client(&self, cluster_id: &str) -> ApiResult<Option<Arc<KafkaConnectClient>>> {
+        let cluster = self.get_cluster(cluster_id).await?;
+        Ok(cluster.kafka_connect_client.as_ref().cloned())
+    }
+
     fn build_new_cluster(&self, cluster_id: &str, error_callback: ErrorCallback<ApiError>) -> ApiResult<Cluster> {
         debug!("Init cluster {}", cluster_id);
         let configuration = self.configuration_provider.get_configuration()?;
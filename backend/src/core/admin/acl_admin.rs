@@ -0,0 +1,471 @@
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    ptr, slice,
+};
+
+use log::debug;
+use rdkafka::{
+    bindings::{
+        rd_kafka_AclBindingFilter_new, rd_kafka_AclBindingFilter_t, rd_kafka_AclBinding_destroy,
+        rd_kafka_AclBinding_host, rd_kafka_AclBinding_name, rd_kafka_AclBinding_new, rd_kafka_AclBinding_operation,
+        rd_kafka_AclBinding_permission_type, rd_kafka_AclBinding_principal, rd_kafka_AclBinding_resource_pattern_type,
+        rd_kafka_AclBinding_restype, rd_kafka_AclBinding_t, rd_kafka_AclOperation_t, rd_kafka_AclPermissionType_t,
+        rd_kafka_AdminOptions_destroy, rd_kafka_AdminOptions_new, rd_kafka_AdminOptions_set_request_timeout,
+        rd_kafka_AdminOptions_t, rd_kafka_CreateAcls, rd_kafka_CreateAcls_result_acls, rd_kafka_DeleteAcls,
+        rd_kafka_DeleteAcls_result_response_error, rd_kafka_DeleteAcls_result_response_matching_acls,
+        rd_kafka_DeleteAcls_result_responses, rd_kafka_DescribeAcls, rd_kafka_DescribeAcls_result_acls,
+        rd_kafka_ResourcePatternType_t, rd_kafka_ResourceType_t, rd_kafka_acl_result_error, rd_kafka_admin_op_t,
+        rd_kafka_error_string, rd_kafka_error_t, rd_kafka_event_CreateAcls_result, rd_kafka_event_DeleteAcls_result,
+        rd_kafka_event_DescribeAcls_result, rd_kafka_event_destroy, rd_kafka_event_error, rd_kafka_event_error_string,
+        rd_kafka_event_t, rd_kafka_queue_destroy, rd_kafka_queue_new, rd_kafka_queue_poll, rd_kafka_queue_t,
+        rd_kafka_resp_err_t, rd_kafka_t,
+    },
+    types::RDKafkaErrorCode,
+};
+
+use super::{
+    error::{AdminError, AdminResult},
+    types::{AclBinding, AclBindingFilter, AclOperation, AclPatternType, AclPermissionType, AclResourceType},
+    KafkaAdmin,
+};
+
+/// The ACL admin operations are not available in the rdkafka admin client,
+/// they are implemented on top of the librdkafka bindings
+impl KafkaAdmin {
+    /// Retrieve the ACL bindings matching the filter. The default filter returns all the bindings
+    pub async fn describe_acls(&self, filter: &AclBindingFilter) -> AdminResult<Vec<AclBinding>> {
+        debug!("Describe ACLs matching {:?}", filter);
+        let operation = rd_kafka_admin_op_t::RD_KAFKA_ADMIN_OP_DESCRIBEACLS;
+        let queue = {
+            let filter = new_acl_binding_filter(filter)?;
+            self.start_admin_operation(operation, |rk, options, queue| unsafe {
+                rd_kafka_DescribeAcls(rk, filter.ptr, options, queue)
+            })?
+        };
+        let event = self.wait_admin_result(operation, queue).await?;
+        unsafe {
+            let result = rd_kafka_event_DescribeAcls_result(event.ptr);
+            let mut count = 0;
+            let acls = rd_kafka_DescribeAcls_result_acls(result, &mut count);
+            Ok(native_array(acls, count)
+                .iter()
+                .map(|acl| read_acl_binding(*acl))
+                .collect())
+        }
+    }
+
+    pub async fn create_acls(&self, acls: &[AclBinding]) -> AdminResult<()> {
+        debug!("Create ACLs {:?}", acls);
+        let operation = rd_kafka_admin_op_t::RD_KAFKA_ADMIN_OP_CREATEACLS;
+        let queue = {
+            let bindings = acls.iter().map(new_acl_binding).collect::<AdminResult<Vec<_>>>()?;
+            let mut binding_ptrs: Vec<_> = bindings.iter().map(|b| b.ptr).collect();
+            self.start_admin_operation(operation, |rk, options, queue| unsafe {
+                rd_kafka_CreateAcls(rk, binding_ptrs.as_mut_ptr(), binding_ptrs.len(), options, queue)
+            })?
+        };
+        let event = self.wait_admin_result(operation, queue).await?;
+        // the results are in the same order of the request
+        let errors: Vec<_> = unsafe {
+            let result = rd_kafka_event_CreateAcls_result(event.ptr);
+            let mut count = 0;
+            let results = rd_kafka_CreateAcls_result_acls(result, &mut count);
+            native_array(results, count)
+                .iter()
+                .zip(acls)
+                .filter_map(|(res, acl)| read_error(rd_kafka_acl_result_error(*res)).map(|err| format!("{acl:?}: {err}")))
+                .collect()
+        };
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AdminError::RDKafka(format!(
+                "Unable to create the ACLs. {}",
+                errors.join("\n")
+            )))
+        }
+    }
+
+    /// Delete all the ACL bindings matching the filters. Returns the deleted bindings
+    pub async fn delete_acls(&self, filters: &[AclBindingFilter]) -> AdminResult<Vec<AclBinding>> {
+        debug!("Delete ACLs matching {:?}", filters);
+        let operation = rd_kafka_admin_op_t::RD_KAFKA_ADMIN_OP_DELETEACLS;
+        let queue = {
+            let filters = filters
+                .iter()
+                .map(new_acl_binding_filter)
+                .collect::<AdminResult<Vec<_>>>()?;
+            let mut filter_ptrs: Vec<_> = filters.iter().map(|f| f.ptr).collect();
+            self.start_admin_operation(operation, |rk, options, queue| unsafe {
+                rd_kafka_DeleteAcls(rk, filter_ptrs.as_mut_ptr(), filter_ptrs.len(), options, queue)
+            })?
+        };
+        let event = self.wait_admin_result(operation, queue).await?;
+        unsafe {
+            let result = rd_kafka_event_DeleteAcls_result(event.ptr);
+            let mut count = 0;
+            let responses = rd_kafka_DeleteAcls_result_responses(result, &mut count);
+            let mut deleted = vec![];
+            for response in native_array(responses, count) {
+                if let Some(err) = read_error(rd_kafka_DeleteAcls_result_response_error(*response)) {
+                    return Err(AdminError::RDKafka(format!("Unable to delete the ACLs. {err}")));
+                }
+                let mut matching_count = 0;
+                let matching = rd_kafka_DeleteAcls_result_response_matching_acls(*response, &mut matching_count);
+                deleted.extend(
+                    native_array(matching, matching_count)
+                        .iter()
+                        .map(|acl| read_acl_binding(*acl)),
+                );
+            }
+            Ok(deleted)
+        }
+    }
+
+    /// Start the admin operation on a dedicated queue, that receives the result event.
+    /// librdkafka copies the request, so the arguments can be dropped once the operation is started
+    fn start_admin_operation(
+        &self,
+        operation: rd_kafka_admin_op_t,
+        start: impl FnOnce(*mut rd_kafka_t, *const rd_kafka_AdminOptions_t, *mut rd_kafka_queue_t),
+    ) -> AdminResult<NativeResource<rd_kafka_queue_t>> {
+        let rk = self.admin_client.inner().native_ptr();
+        let timeout_ms = self.timeout.as_millis() as i32;
+        unsafe {
+            let queue = NativeResource::new(rd_kafka_queue_new(rk), rd_kafka_queue_destroy)?;
+            let options = NativeResource::new(rd_kafka_AdminOptions_new(rk, operation), rd_kafka_AdminOptions_destroy)?;
+            let mut errstr = [0 as c_char; 512];
+            let err =
+                rd_kafka_AdminOptions_set_request_timeout(options.ptr, timeout_ms, errstr.as_mut_ptr(), errstr.len());
+            if err != rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+                return Err(AdminError::RDKafka(read_string(errstr.as_ptr())));
+            }
+            start(rk, options.ptr, queue.ptr);
+            Ok(queue)
+        }
+    }
+
+    /// Wait for the result event of the operation started on the queue.
+    /// The poll blocks, so it runs off the async runtime
+    async fn wait_admin_result(
+        &self,
+        operation: rd_kafka_admin_op_t,
+        queue: NativeResource<rd_kafka_queue_t>,
+    ) -> AdminResult<NativeResource<rd_kafka_event_t>> {
+        let admin_client = self.admin_client.clone();
+        let timeout_ms = self.timeout.as_millis() as i32;
+        let event = tokio::task::spawn_blocking(move || {
+            // the request timeout is handled by librdkafka, that returns an error event
+            // SAFETY: the queue is valid while the client is alive, kept alive by the Arc
+            let event = unsafe { rd_kafka_queue_poll(queue.ptr, timeout_ms * 2) };
+            drop(queue);
+            drop(admin_client);
+            NativeResource::new(event, rd_kafka_event_destroy)
+        })
+        .await
+        .map_err(|err| AdminError::RDKafka(format!("Unable to wait for the {operation:?} result: {err}")))?
+        .map_err(|_| AdminError::RDKafka(format!("Timeout waiting for the {operation:?} result")))?;
+        unsafe {
+            let err = rd_kafka_event_error(event.ptr);
+            if err != rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+                return Err(AdminError::RDKafka(format!(
+                    "{}: {}",
+                    RDKafkaErrorCode::from(err),
+                    read_string(rd_kafka_event_error_string(event.ptr))
+                )));
+            }
+        }
+        Ok(event)
+    }
+}
+
+/// Owned librdkafka object, destroyed on drop
+struct NativeResource<T> {
+    ptr: *mut T,
+    destroy: unsafe extern "C" fn(*mut T),
+}
+
+impl<T> NativeResource<T> {
+    fn new(ptr: *mut T, destroy: unsafe extern "C" fn(*mut T)) -> AdminResult<Self> {
+        if ptr.is_null() {
+            Err(AdminError::RDKafka("Unable to allocate the librdkafka object".into()))
+        } else {
+            Ok(Self { ptr, destroy })
+        }
+    }
+}
+
+// SAFETY: librdkafka queues and events can be used and destroyed from any thread
+unsafe impl Send for NativeResource<rd_kafka_queue_t> {}
+unsafe impl Send for NativeResource<rd_kafka_event_t> {}
+
+impl<T> Drop for NativeResource<T> {
+    fn drop(&mut self) {
+        unsafe { (self.destroy)(self.ptr) }
+    }
+}
+
+fn new_acl_binding(acl: &AclBinding) -> AdminResult<NativeResource<rd_kafka_AclBinding_t>> {
+    let name = to_cstring(&acl.resource_name)?;
+    let principal = to_cstring(&acl.principal)?;
+    let host = to_cstring(&acl.host)?;
+    let mut errstr = [0 as c_char; 512];
+    let ptr = unsafe {
+        rd_kafka_AclBinding_new(
+            acl.resource_type.into(),
+            name.as_ptr(),
+            acl.pattern_type.into(),
+            principal.as_ptr(),
+            host.as_ptr(),
+            acl.operation.into(),
+            acl.permission_type.into(),
+            errstr.as_mut_ptr(),
+            errstr.len(),
+        )
+    };
+    // the error buffer is zero initialized, so it is always nul terminated
+    NativeResource::new(ptr, rd_kafka_AclBinding_destroy).map_err(|_| {
+        AdminError::InvalidConfiguration(format!("Invalid ACL {acl:?}: {}", unsafe {
+            read_string(errstr.as_ptr())
+        }))
+    })
+}
+
+fn new_acl_binding_filter(filter: &AclBindingFilter) -> AdminResult<NativeResource<rd_kafka_AclBindingFilter_t>> {
+    let name = filter.resource_name.as_deref().map(to_cstring).transpose()?;
+    let principal = filter.principal.as_deref().map(to_cstring).transpose()?;
+    let host = filter.host.as_deref().map(to_cstring).transpose()?;
+    // null pointers match any value
+    let as_ptr = |s: &Option<CString>| s.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null());
+    let mut errstr = [0 as c_char; 512];
+    let ptr = unsafe {
+        rd_kafka_AclBindingFilter_new(
+            filter.resource_type.into(),
+            as_ptr(&name),
+            filter.pattern_type.into(),
+            as_ptr(&principal),
+            as_ptr(&host),
+            filter.operation.into(),
+            filter.permission_type.into(),
+            errstr.as_mut_ptr(),
+            errstr.len(),
+        )
+    };
+    NativeResource::new(ptr, rd_kafka_AclBinding_destroy).map_err(|_| {
+        AdminError::InvalidConfiguration(format!("Invalid ACL filter {filter:?}: {}", unsafe {
+            read_string(errstr.as_ptr())
+        }))
+    })
+}
+
+fn to_cstring(value: &str) -> AdminResult<CString> {
+    CString::new(value).map_err(|_| AdminError::InvalidConfiguration(format!("Invalid ACL field {value}")))
+}
+
+/// Safety: the pointer must be null or point to a valid nul terminated string
+unsafe fn read_string(value: *const c_char) -> String {
+    if value.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(value).to_string_lossy().into_owned()
+    }
+}
+
+/// Safety: the pointer must be null or point to a valid librdkafka error
+unsafe fn read_error(error: *const rd_kafka_error_t) -> Option<String> {
+    if error.is_null() {
+        None
+    } else {
+        Some(read_string(rd_kafka_error_string(error)))
+    }
+}
+
+/// Safety: the pointer must be null or point to an array of count elements
+unsafe fn native_array<'a, T>(array: *mut *const T, count: usize) -> &'a [*const T] {
+    if array.is_null() || count == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(array, count)
+    }
+}
+
+/// Safety: the pointer must point to a valid ACL binding
+unsafe fn read_acl_binding(acl: *const rd_kafka_AclBinding_t) -> AclBinding {
+    AclBinding {
+        resource_type: rd_kafka_AclBinding_restype(acl).into(),
+        resource_name: read_string(rd_kafka_AclBinding_name(acl)),
+        pattern_type: rd_kafka_AclBinding_resource_pattern_type(acl).into(),
+        principal: read_string(rd_kafka_AclBinding_principal(acl)),
+        host: read_string(rd_kafka_AclBinding_host(acl)),
+        operation: rd_kafka_AclBinding_operation(acl).into(),
+        permission_type: rd_kafka_AclBinding_permission_type(acl).into(),
+    }
+}
+
+impl From<AclResourceType> for rd_kafka_ResourceType_t {
+    fn from(value: AclResourceType) -> Self {
+        match value {
+            AclResourceType::Unknown => rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_UNKNOWN,
+            AclResourceType::Any => rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_ANY,
+            AclResourceType::Topic => rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_TOPIC,
+            AclResourceType::Group => rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_GROUP,
+            AclResourceType::Cluster => rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_BROKER,
+        }
+    }
+}
+
+impl From<rd_kafka_ResourceType_t> for AclResourceType {
+    fn from(value: rd_kafka_ResourceType_t) -> Self {
+        match value {
+            rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_ANY => AclResourceType::Any,
+            rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_TOPIC => AclResourceType::Topic,
+            rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_GROUP => AclResourceType::Group,
+            rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_BROKER => AclResourceType::Cluster,
+            _ => AclResourceType::Unknown,
+        }
+    }
+}
+
+impl From<AclPatternType> for rd_kafka_ResourcePatternType_t {
+    fn from(value: AclPatternType) -> Self {
+        match value {
+            AclPatternType::Unknown => rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_UNKNOWN,
+            AclPatternType::Any => rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_ANY,
+            AclPatternType::Match => rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_MATCH,
+            AclPatternType::Literal => rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_LITERAL,
+            AclPatternType::Prefixed => rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_PREFIXED,
+        }
+    }
+}
+
+impl From<rd_kafka_ResourcePatternType_t> for AclPatternType {
+    fn from(value: rd_kafka_ResourcePatternType_t) -> Self {
+        match value {
+            rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_ANY => AclPatternType::Any,
+            rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_MATCH => AclPatternType::Match,
+            rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_LITERAL => AclPatternType::Literal,
+            rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_PREFIXED => AclPatternType::Prefixed,
+            _ => AclPatternType::Unknown,
+        }
+    }
+}
+
+impl From<AclOperation> for rd_kafka_AclOperation_t {
+    fn from(value: AclOperation) -> Self {
+        match value {
+            AclOperation::Unknown => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_UNKNOWN,
+            AclOperation::Any => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ANY,
+            AclOperation::All => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALL,
+            AclOperation::Read => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_READ,
+            AclOperation::Write => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_WRITE,
+            AclOperation::Create => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CREATE,
+            AclOperation::Delete => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DELETE,
+            AclOperation::Alter => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER,
+            AclOperation::Describe => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE,
+            AclOperation::ClusterAction => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CLUSTER_ACTION,
+            AclOperation::DescribeConfigs => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE_CONFIGS,
+            AclOperation::AlterConfigs => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER_CONFIGS,
+            AclOperation::IdempotentWrite => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_IDEMPOTENT_WRITE,
+        }
+    }
+}
+
+impl From<rd_kafka_AclOperation_t> for AclOperation {
+    fn from(value: rd_kafka_AclOperation_t) -> Self {
+        match value {
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ANY => AclOperation::Any,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALL => AclOperation::All,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_READ => AclOperation::Read,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_WRITE => AclOperation::Write,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CREATE => AclOperation::Create,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DELETE => AclOperation::Delete,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER => AclOperation::Alter,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE => AclOperation::Describe,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CLUSTER_ACTION => AclOperation::ClusterAction,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE_CONFIGS => AclOperation::DescribeConfigs,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER_CONFIGS => AclOperation::AlterConfigs,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_IDEMPOTENT_WRITE => AclOperation::IdempotentWrite,
+            _ => AclOperation::Unknown,
+        }
+    }
+}
+
+impl From<AclPermissionType> for rd_kafka_AclPermissionType_t {
+    fn from(value: AclPermissionType) -> Self {
+        match value {
+            AclPermissionType::Unknown => rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_UNKNOWN,
+            AclPermissionType::Any => rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ANY,
+            AclPermissionType::Deny => rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_DENY,
+            AclPermissionType::Allow => rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ALLOW,
+        }
+    }
+}
+
+impl From<rd_kafka_AclPermissionType_t> for AclPermissionType {
+    fn from(value: rd_kafka_AclPermissionType_t) -> Self {
+        match value {
+            rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ANY => AclPermissionType::Any,
+            rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_DENY => AclPermissionType::Deny,
+            rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ALLOW => AclPermissionType::Allow,
+            _ => AclPermissionType::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::admin::{
+        AclBinding, AclBindingFilter, AclOperation, AclPatternType, AclPermissionType, AclResourceType,
+    };
+
+    use super::{new_acl_binding, new_acl_binding_filter, read_acl_binding};
+
+    #[test]
+    fn test_acl_binding_round_trip() {
+        let acl = AclBinding {
+            resource_type: AclResourceType::Topic,
+            resource_name: "orders".into(),
+            pattern_type: AclPatternType::Prefixed,
+            principal: "User:alice".into(),
+            host: "*".into(),
+            operation: AclOperation::Read,
+            permission_type: AclPermissionType::Deny,
+        };
+        let native = new_acl_binding(&acl).unwrap();
+        assert_eq!(unsafe { read_acl_binding(native.ptr) }, acl);
+    }
+
+    #[test]
+    fn test_invalid_acl_binding() {
+        // the bindings can't use the filter only values
+        let acl = AclBinding {
+            resource_type: AclResourceType::Any,
+            resource_name: "orders".into(),
+            pattern_type: AclPatternType::Match,
+            principal: "User:alice".into(),
+            host: "*".into(),
+            operation: AclOperation::Any,
+            permission_type: AclPermissionType::Any,
+        };
+        assert!(new_acl_binding(&acl).is_err());
+        assert!(new_acl_binding(&AclBinding {
+            principal: "User:\0alice".into(),
+            ..acl
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_acl_binding_filter() {
+        let filter = AclBindingFilter {
+            principal: Some("User:alice".into()),
+            ..Default::default()
+        };
+        let native = new_acl_binding_filter(&filter).unwrap();
+        let res = unsafe { read_acl_binding(native.ptr) };
+        assert_eq!(res.principal, "User:alice");
+        assert_eq!(res.resource_name, "");
+        assert_eq!(res.resource_type, AclResourceType::Any);
+    }
+}
@@ -13,8 +13,8 @@ use super::error::AdminResult;
 pub struct KafkaAdmin {
     pub(super) config: ClusterConfig,
     pub(super) timeout: Duration,
-    pub(super) consumer: BaseConsumer,
-    pub(super) admin_client: AdminClient<DefaultClientContext>,
+    pub(super) consumer: Arc<BaseConsumer>,
+    pub(super) admin_client: Arc<AdminClient<DefaultClientContext>>,
     pub(super) all_topic_partition_list: Arc<RwLock<TopicPartitionList>>,
 }
 
@@ -23,8 +23,8 @@ impl KafkaAdmin {
         Ok(KafkaAdmin {
             config: config.clone(),
             timeout: kafka_timeout,
-            consumer: build_kafka_client_config(config, None).create()?,
-            admin_client: build_kafka_client_config(config, None).create()?,
+            consumer: Arc::new(build_kafka_client_config(config, None).create()?),
+            admin_client: Arc::new(build_kafka_client_config(config, None).create()?),
             all_topic_partition_list: Arc::new(RwLock::new(TopicPartitionList::new())),
         })
     }
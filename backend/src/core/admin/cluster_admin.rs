@@ -0,0 +1,118 @@
+use std::{collections::HashMap, time::Duration};
+
+use futures::future::join_all;
+use log::{debug, warn};
+use rdkafka::{
+    admin::{AdminOptions, ResourceSpecifier},
+    bindings::rd_kafka_controllerid,
+    consumer::Consumer,
+};
+
+use super::{
+    error::{AdminError, AdminResult},
+    types::{BrokerInfo, ClusterInfo},
+    KafkaAdmin,
+};
+
+const BROKER_RACK_CONFIG: &str = "broker.rack";
+/// Max time to wait for the controller id, the cluster is described without it if not available in time
+const CONTROLLER_ID_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl KafkaAdmin {
+    pub async fn describe_cluster(&self) -> AdminResult<ClusterInfo> {
+        debug!("Describe the cluster");
+        let metadata = self.consumer.fetch_metadata(None, self.timeout)?;
+        let controller_id = self.get_controller_id().await;
+        let broker_ids: Vec<_> = metadata.brokers().iter().map(|b| b.id()).collect();
+        let racks = self.get_broker_racks(&broker_ids).await;
+        let brokers = metadata
+            .brokers()
+            .iter()
+            .map(|b| BrokerInfo {
+                id: b.id(),
+                host: b.host().into(),
+                port: b.port(),
+                rack: racks.get(&b.id()).cloned(),
+                is_controller: controller_id == Some(b.id()),
+            })
+            .collect();
+        let partitions: Vec<_> = metadata.topics().iter().flat_map(|t| t.partitions()).collect();
+        Ok(ClusterInfo {
+            controller_id,
+            brokers,
+            topics_count: metadata.topics().len(),
+            partitions_count: partitions.len(),
+            under_replicated_partitions: partitions.iter().filter(|p| p.isr().len() < p.replicas().len()).count(),
+            // a partition without a leader is not available to producers and consumers
+            offline_partitions: partitions.iter().filter(|p| p.leader() < 0).count(),
+        })
+    }
+
+    pub async fn get_broker_configuration(&self, broker_id: i32) -> AdminResult<HashMap<String, Option<String>>> {
+        debug!("Retrieving the configurations of broker {}", broker_id);
+        let responses = self
+            .admin_client
+            .describe_configs([&ResourceSpecifier::Broker(broker_id)], &AdminOptions::default())
+            .await?;
+        match responses.into_iter().next() {
+            Some(Ok(broker_config)) => Ok(broker_config.entries.into_iter().map(|c| (c.name, c.value)).collect()),
+            Some(Err(err)) => Err(err.into()),
+            None => Err(AdminError::RDKafka(format!("Broker {broker_id} not found"))),
+        }
+    }
+
+    async fn get_controller_id(&self) -> Option<i32> {
+        let consumer = self.consumer.clone();
+        let timeout = self.timeout.min(CONTROLLER_ID_TIMEOUT);
+        // rd_kafka_controllerid blocks until the controller is known or the timeout expires
+        let controller_id = tokio::task::spawn_blocking(move || {
+            // SAFETY: the client pointer is valid for the lifetime of the consumer, kept alive by the Arc
+            unsafe { rd_kafka_controllerid(consumer.client().native_ptr(), timeout.as_millis() as i32) }
+        })
+        .await;
+        match controller_id {
+            Ok(controller_id) if controller_id >= 0 => Some(controller_id),
+            Ok(_) => None,
+            Err(err) => {
+                warn!("Unable to retrieve the controller id: {}", err);
+                None
+            }
+        }
+    }
+
+    /// The rack is only available in the broker configurations. Brokers whose configurations
+    /// can't be retrieved, e.g. because of the ACLs, are skipped.
+    /// librdkafka allows a single broker resource per describe configs request
+    async fn get_broker_racks(&self, broker_ids: &[i32]) -> HashMap<i32, String> {
+        let responses = join_all(broker_ids.iter().map(|id| async move {
+            let response = self
+                .admin_client
+                .describe_configs([&ResourceSpecifier::Broker(*id)], &AdminOptions::default())
+                .await;
+            (*id, response)
+        }))
+        .await;
+        let mut racks = HashMap::new();
+        for (broker_id, response) in responses {
+            let broker_config = match response.map(|r| r.into_iter().next()) {
+                Ok(Some(Ok(broker_config))) => broker_config,
+                Ok(Some(Err(err))) => {
+                    warn!("Unable to retrieve the configurations of broker {}: {}", broker_id, err);
+                    continue;
+                }
+                Ok(None) => {
+                    warn!("Missing configurations of broker {}", broker_id);
+                    continue;
+                }
+                Err(err) => {
+                    warn!("Unable to retrieve the configurations of broker {}: {}", broker_id, err);
+                    continue;
+                }
+            };
+            if let Some(rack) = broker_config.get(BROKER_RACK_CONFIG).and_then(|c| c.value.clone()) {
+                racks.insert(broker_id, rack);
+            }
+        }
+        racks
+    }
+}
@@ -1,16 +1,30 @@
-use log::{debug, trace};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use log::{debug, trace, warn};
 
-use super::{error::AdminResult, ConsumerGroupInfo, KafkaAdmin};
+use super::{
+    consumer_protocol::decode_member_assignment,
+    error::AdminResult,
+    offsets_file::{read_offsets_file, write_offsets_file},
+    AdminError, ConsumerGroupInfo, ConsumerGroupLag, ConsumerGroupMember, ConsumerGroupOffsets, KafkaAdmin, OffsetReset,
+    OffsetResetStrategy, OffsetsFileFormat, PartitionLag,
+};
 use crate::core::{
     admin::TopicPartitionOffset,
-    configuration::build_kafka_client_config,
+    configuration::{build_kafka_client_config, ClusterConfig},
     consumer::{types::ConsumerOffsetConfiguration, KafkaConsumer},
 };
 use rdkafka::{
     admin::AdminOptions,
     consumer::{BaseConsumer, Consumer},
 };
-use rdkafka::{consumer::CommitMode, Offset};
+use rdkafka::{consumer::CommitMode, groups::GroupInfo, message::Message, Offset, TopicPartitionList};
+
+/// Max time to wait for the records used to compute the time lag, that is omitted if not available in time
+const TIME_LAG_TIMEOUT: Duration = Duration::from_secs(2);
 
 impl KafkaAdmin {
     pub async fn delete_consumer_group(&self, consumer_group_name: &str) -> AdminResult<()> {
@@ -35,23 +49,228 @@ impl KafkaAdmin {
         let consumer = build_kafka_client_config(&self.config, Some(consumer_group_name)).create()?;
 
         debug!("assign offsets for each topic");
-        KafkaConsumer::update_consumer_assignment(&consumer, topic_names, offset_configuration, self.timeout)?;
+        KafkaConsumer::update_consumer_assignment(&consumer, topic_names, offset_configuration, None, self.timeout)?;
 
         debug!("store offset to commit");
         for t in consumer.assignment()?.elements() {
+            // the logical offsets can't be committed, resolve them to the partition watermarks
+            let offset = match t.offset() {
+                Offset::Offset(offset) => offset,
+                Offset::Beginning => consumer.fetch_watermarks(t.topic(), t.partition(), self.timeout)?.0,
+                Offset::End => consumer.fetch_watermarks(t.topic(), t.partition(), self.timeout)?.1,
+                offset => {
+                    return Err(AdminError::InvalidConfiguration(format!(
+                        "Unable to commit the offset {offset:?} for topic {} partition {}",
+                        t.topic(),
+                        t.partition()
+                    )))
+                }
+            };
             trace!(
                 "Store topic {:?} partition {:?} offset {:?}",
                 t.topic(),
                 t.partition(),
-                t.offset().to_raw()
+                offset
             );
-            consumer.store_offset(t.topic(), t.partition(), t.offset().to_raw().unwrap() - 1)?;
+            // the stored offset is the last consumed one, the next to consume is committed
+            consumer.store_offset(t.topic(), t.partition(), offset - 1)?;
         }
 
         debug!("commit consumer state");
         Ok(consumer.commit_consumer_state(CommitMode::Sync)?)
     }
 
+    /// Reset the offsets committed by the consumer group on the topic, following the kafka-consumer-groups tool.
+    /// Only the specified partition ids are reset, all the partitions if None. Partition ids that don't exist
+    /// in the topic are rejected, including the ones in the strategy. The new offsets are
+    /// clamped to the available records. Partitions without a committed offset are treated as
+    /// committed to the earliest offset by ShiftBy and Current.
+    /// With dry_run the new offsets are computed and returned without committing them
+    pub async fn reset_consumer_group_offsets(
+        &self,
+        consumer_group_name: &str,
+        topic_name: &str,
+        strategy: &OffsetResetStrategy,
+        partitions: Option<&[i32]>,
+        dry_run: bool,
+    ) -> AdminResult<Vec<OffsetReset>> {
+        let topic = self.get_topic(topic_name)?;
+        let strategy_partitions = match strategy {
+            OffsetResetStrategy::Offsets(offsets) | OffsetResetStrategy::Timestamps(offsets) => Some(offsets),
+            _ => None,
+        };
+        let mut unknown_partitions: Vec<_> = partitions
+            .unwrap_or_default()
+            .iter()
+            .chain(strategy_partitions.into_iter().flat_map(|offsets| offsets.keys()))
+            .filter(|id| !topic.partitions.iter().any(|p| p.id == **id))
+            .copied()
+            .collect();
+        if !unknown_partitions.is_empty() {
+            unknown_partitions.sort();
+            unknown_partitions.dedup();
+            return Err(AdminError::InvalidConfiguration(format!(
+                "The partitions {unknown_partitions:?} don't exist in the topic {topic_name}"
+            )));
+        }
+        let partition_ids: Vec<_> = topic
+            .partitions
+            .iter()
+            .map(|p| p.id)
+            .filter(|id| partitions.map(|partitions| partitions.contains(id)).unwrap_or(true))
+            .filter(|id| {
+                strategy_partitions
+                    .map(|offsets| offsets.contains_key(id))
+                    .unwrap_or(true)
+            })
+            .collect();
+        if partition_ids.is_empty() {
+            return Err(AdminError::InvalidConfiguration(format!(
+                "No partitions to reset for the topic {topic_name} and partitions {partitions:?}"
+            )));
+        }
+        if !dry_run {
+            // the group coordinator rejects the offsets committed outside of the group while it has members
+            let state = self.get_consumer_group_state(consumer_group_name)?;
+            if state != "Empty" && state != "Dead" {
+                return Err(AdminError::InvalidConfiguration(format!(
+                    "The consumer group {consumer_group_name} must be inactive to reset the offsets, current state: {state}"
+                )));
+            }
+        }
+        // the consumer shouldn't join the consumer group, otherwise it'll cause a re-balance
+        let consumer: BaseConsumer = build_kafka_client_config(&self.config, Some(consumer_group_name)).create()?;
+        let mut tp = TopicPartitionList::new();
+        for id in &partition_ids {
+            tp.add_partition(topic_name, *id);
+        }
+        let committed_offsets = consumer.committed_offsets(tp.clone(), self.timeout)?;
+        let timestamp_offsets = match strategy {
+            OffsetResetStrategy::Timestamp(timestamp) => {
+                tp.set_all_offsets(Offset::Offset(*timestamp))?;
+                Some(self.consumer.offsets_for_times(tp, self.timeout)?)
+            }
+            OffsetResetStrategy::Timestamps(timestamps) => {
+                for id in &partition_ids {
+                    tp.set_partition_offset(topic_name, *id, Offset::Offset(timestamps[id]))?;
+                }
+                Some(self.consumer.offsets_for_times(tp, self.timeout)?)
+            }
+            _ => None,
+        };
+
+        let mut res = vec![];
+        for id in partition_ids {
+            let (low, high) = self.consumer.fetch_watermarks(topic_name, id, self.timeout)?;
+            let current_offset = committed_offsets
+                .find_partition(topic_name, id)
+                .and_then(|tpo| tpo.offset().to_raw())
+                .filter(|offset| *offset >= 0);
+            let new_offset = match strategy {
+                OffsetResetStrategy::Earliest => low,
+                OffsetResetStrategy::Latest => high,
+                OffsetResetStrategy::Offsets(offsets) => offsets[&id],
+                OffsetResetStrategy::ShiftBy(shift) => current_offset.unwrap_or(low) + shift,
+                OffsetResetStrategy::Timestamp(_) | OffsetResetStrategy::Timestamps(_) => {
+                    match timestamp_offsets
+                        .as_ref()
+                        .and_then(|tp| tp.find_partition(topic_name, id))
+                    {
+                        Some(tpo) => {
+                            tpo.error()?;
+                            match tpo.offset() {
+                                Offset::Offset(offset) => offset,
+                                // no records produced after the timestamp
+                                _ => high,
+                            }
+                        }
+                        None => high,
+                    }
+                }
+                OffsetResetStrategy::Current => current_offset.unwrap_or(low),
+            };
+            res.push(OffsetReset {
+                topic: topic_name.into(),
+                partition_id: id,
+                current_offset,
+                new_offset: new_offset.clamp(low, high),
+            });
+        }
+
+        if !dry_run {
+            debug!("Commit the new offsets {:?}", res);
+            let mut tp = TopicPartitionList::new();
+            for r in &res {
+                tp.add_partition_offset(&r.topic, r.partition_id, Offset::Offset(r.new_offset))?;
+            }
+            consumer.commit(&tp, CommitMode::Sync)?;
+        }
+        Ok(res)
+    }
+
+    /// Write the offsets committed by the consumer group to file
+    pub async fn export_consumer_group_offsets(
+        &self,
+        consumer_group_name: &str,
+        output_path: &str,
+        format: OffsetsFileFormat,
+        overwrite: bool,
+    ) -> AdminResult<ConsumerGroupOffsets> {
+        let ConsumerGroupInfo { name, offsets, .. } = self.describe_consumer_group(consumer_group_name, true).await?;
+        let offsets = ConsumerGroupOffsets {
+            consumer_group: name,
+            offsets,
+        };
+        write_offsets_file(output_path, &offsets, format, overwrite)?;
+        Ok(offsets)
+    }
+
+    /// Restore the offsets exported to file into the consumer group, that may be different from
+    /// the exported one. Returns the difference with the offsets currently committed.
+    /// With dry_run nothing is committed. All the topics are validated before committing any offset,
+    /// the import fails if the file contains partitions that don't exist anymore
+    pub async fn import_consumer_group_offsets(
+        &self,
+        consumer_group_name: &str,
+        input_path: &str,
+        format: OffsetsFileFormat,
+        dry_run: bool,
+    ) -> AdminResult<Vec<OffsetReset>> {
+        let ConsumerGroupOffsets { offsets, .. } = read_offsets_file(input_path, format)?;
+        let mut topic_offsets: HashMap<String, HashMap<i32, i64>> = HashMap::new();
+        for o in offsets {
+            topic_offsets
+                .entry(o.topic)
+                .or_default()
+                .insert(o.partition_id, o.offset);
+        }
+        let mut topics: Vec<_> = topic_offsets.into_iter().collect();
+        topics.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut res = vec![];
+        for (topic, offsets) in &topics {
+            let strategy = OffsetResetStrategy::Offsets(offsets.clone());
+            res.extend(
+                self.reset_consumer_group_offsets(consumer_group_name, topic, &strategy, None, true)
+                    .await
+                    .map_err(|err| match err {
+                        AdminError::InvalidConfiguration(message) => AdminError::InvalidOffsetsFile(format!(
+                            "Unable to restore the offsets from {input_path}. {message}"
+                        )),
+                        err => err,
+                    })?,
+            );
+        }
+        if !dry_run {
+            for (topic, offsets) in topics {
+                let strategy = OffsetResetStrategy::Offsets(offsets);
+                self.reset_consumer_group_offsets(consumer_group_name, &topic, &strategy, None, false)
+                    .await?;
+            }
+        }
+        Ok(res)
+    }
+
     pub fn list_consumer_groups(&self) -> AdminResult<Vec<String>> {
         let groups = self.consumer.fetch_group_list(None, self.timeout)?;
         let group_names: Vec<_> = groups.groups().iter().map(|g| g.name().to_string()).collect();
@@ -89,20 +308,155 @@ impl KafkaAdmin {
                 offset: r.offset().to_raw().unwrap(),
             })
             .collect();
+
+        debug!("Retrieve the consumer group members");
+        let group_list = self
+            .consumer
+            .fetch_group_list(Some(consumer_group_name), self.timeout)?;
+        let group = Self::find_group(group_list.groups(), consumer_group_name)?;
+        let members = group
+            .members()
+            .iter()
+            .map(|m| ConsumerGroupMember {
+                id: m.id().into(),
+                client_id: m.client_id().into(),
+                host: m.client_host().into(),
+                assignments: match (group.protocol_type(), m.assignment()) {
+                    ("consumer", Some(assignment)) => decode_member_assignment(assignment).unwrap_or_else(|| {
+                        warn!("Unable to decode the assignment of the member {}", m.id());
+                        vec![]
+                    }),
+                    _ => vec![],
+                },
+            })
+            .collect();
         debug!("Retrieve completed");
         Ok(ConsumerGroupInfo {
             name: consumer_group_name.into(),
+            state: group.state().into(),
+            protocol_type: group.protocol_type().into(),
+            protocol: group.protocol().into(),
+            members,
             offsets,
         })
     }
 
+    /// Compute the lag of each partition with a committed offset, comparing it with the high watermark.
+    /// The time lag is computed from the timestamp of the record at the committed offset
+    pub async fn get_consumer_group_lag(
+        &self,
+        consumer_group_name: &str,
+        ignore_cache: bool,
+    ) -> AdminResult<ConsumerGroupLag> {
+        let ConsumerGroupInfo { name, offsets, .. } =
+            self.describe_consumer_group(consumer_group_name, ignore_cache).await?;
+        debug!("Retrieve the watermarks of the partitions");
+        let mut partitions = vec![];
+        let mut next_offsets = vec![];
+        for tpo in offsets {
+            let (low, high) = self
+                .consumer
+                .fetch_watermarks(&tpo.topic, tpo.partition_id, self.timeout)?;
+            // the records before the low watermark have been deleted and won't be consumed
+            let next_offset = tpo.offset.max(low);
+            let lag = (high - next_offset).max(0);
+            if lag > 0 {
+                next_offsets.push((tpo.topic.clone(), tpo.partition_id, next_offset));
+            }
+            partitions.push(PartitionLag {
+                topic: tpo.topic,
+                partition_id: tpo.partition_id,
+                committed_offset: tpo.offset,
+                end_offset: high,
+                lag,
+                time_lag_ms: None,
+            });
+        }
+        debug!("Retrieve the timestamps of the records at the committed offsets");
+        let timestamps = self.get_record_timestamps(next_offsets).await.unwrap_or_else(|err| {
+            warn!("Unable to retrieve the record timestamps: {:?}", err);
+            HashMap::new()
+        });
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64;
+        for p in partitions.iter_mut() {
+            p.time_lag_ms = timestamps
+                .get(&(p.topic.clone(), p.partition_id))
+                .map(|timestamp| (now - timestamp).max(0));
+        }
+        Ok(ConsumerGroupLag {
+            name,
+            total_lag: partitions.iter().map(|p| p.lag).sum(),
+            max_time_lag_ms: partitions.iter().filter_map(|p| p.time_lag_ms).max(),
+            partitions,
+        })
+    }
+
+    /// Read the first record available at the specified (topic, partition, offset) positions
+    /// and return its timestamp in ms. Partitions without records within the timeout are skipped.
+    /// The consumer polls, so it runs off the async runtime
+    async fn get_record_timestamps(
+        &self,
+        positions: Vec<(String, i32, i64)>,
+    ) -> AdminResult<HashMap<(String, i32), i64>> {
+        if positions.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let config = self.config.clone();
+        let timeout = self.timeout.min(TIME_LAG_TIMEOUT);
+        tokio::task::spawn_blocking(move || Self::read_record_timestamps(&config, &positions, timeout))
+            .await
+            .map_err(|err| AdminError::RDKafka(format!("Unable to read the record timestamps: {err}")))?
+    }
+
+    fn read_record_timestamps(
+        config: &ClusterConfig,
+        positions: &[(String, i32, i64)],
+        timeout: Duration,
+    ) -> AdminResult<HashMap<(String, i32), i64>> {
+        // the consumer is only assigned to the partitions, so it doesn't join any consumer group
+        let consumer: BaseConsumer = build_kafka_client_config(config, None).create()?;
+        let mut tp = TopicPartitionList::new();
+        for (topic, partition, offset) in positions {
+            tp.add_partition_offset(topic, *partition, Offset::Offset(*offset))?;
+        }
+        consumer.assign(&tp)?;
+        let mut timestamps = HashMap::new();
+        let mut received = 0;
+        let deadline = Instant::now() + timeout;
+        while received < positions.len() && Instant::now() < deadline {
+            match consumer.poll(Duration::from_millis(200)) {
+                Some(Ok(msg)) => {
+                    let key = (msg.topic().to_string(), msg.partition());
+                    if let Entry::Vacant(entry) = timestamps.entry(key) {
+                        received += 1;
+                        entry.insert(msg.timestamp().to_millis());
+                    }
+                }
+                Some(Err(err)) => warn!("Unable to retrieve the record timestamp: {}", err),
+                None => {}
+            }
+        }
+        // records without a timestamp are skipped
+        Ok(timestamps
+            .into_iter()
+            .filter_map(|(k, timestamp)| timestamp.map(|t| (k, t)))
+            .collect())
+    }
+
     pub fn get_consumer_group_state(&self, consumer_group_name: &str) -> AdminResult<String> {
         debug!("Retrieve consumer group status");
         let fetch_group_response = self
             .consumer
             .fetch_group_list(Some(consumer_group_name), self.timeout)?;
-        let groups: Vec<_> = fetch_group_response.groups().iter().collect();
-        assert_eq!(groups.len(), 1);
-        Ok(groups[0].state().to_string())
+        Ok(Self::find_group(fetch_group_response.groups(), consumer_group_name)?
+            .state()
+            .to_string())
+    }
+
+    fn find_group<'a>(groups: &'a [GroupInfo], consumer_group_name: &str) -> AdminResult<&'a GroupInfo> {
+        groups
+            .iter()
+            .find(|g| g.name() == consumer_group_name)
+            .ok_or_else(|| AdminError::ConsumerGroupNotFound(consumer_group_name.into()))
     }
 }
@@ -0,0 +1,144 @@
+use super::MemberAssignment;
+
+/// Decode the member assignment of the consumer protocol
+/// (version: int16, [topic: string, [partition: int32]], user_data: bytes).
+/// All the versions share the same layout for the assigned partitions, the user data is ignored.
+/// Returns None if the bytes are not a valid consumer protocol assignment
+pub(super) fn decode_member_assignment(bytes: &[u8]) -> Option<Vec<MemberAssignment>> {
+    // members without an assignment (e.g. during a re-balance) return an empty payload
+    if bytes.is_empty() {
+        return Some(vec![]);
+    }
+    let mut reader = Reader { bytes, position: 0 };
+    let version = reader.read_i16()?;
+    if version < 0 {
+        return None;
+    }
+    // each topic takes at least the topic name length (2 bytes) and the partitions count (4 bytes)
+    let topics_count = reader.read_array_len(6)?;
+    let mut assignments = vec![];
+    for _ in 0..topics_count {
+        let topic = reader.read_string()?;
+        let partitions_count = reader.read_array_len(4)?;
+        let mut partitions = vec![];
+        for _ in 0..partitions_count {
+            partitions.push(reader.read_i32()?);
+        }
+        assignments.push(MemberAssignment { topic, partitions });
+    }
+    Some(assignments)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let res = self.bytes.get(self.position..self.position + N)?.try_into().ok()?;
+        self.position += N;
+        Some(res)
+    }
+
+    fn read_i16(&mut self) -> Option<i16> {
+        self.read().map(i16::from_be_bytes)
+    }
+
+    fn read_i32(&mut self) -> Option<i32> {
+        self.read().map(i32::from_be_bytes)
+    }
+
+    /// Read the number of items of an array, None if negative or if the
+    /// remaining bytes can't contain the items of `min_item_size` bytes
+    fn read_array_len(&mut self, min_item_size: usize) -> Option<usize> {
+        let len = usize::try_from(self.read_i32()?).ok()?;
+        let remaining = self.bytes.len() - self.position;
+        if len.checked_mul(min_item_size)? > remaining {
+            return None;
+        }
+        Some(len)
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        let len = usize::try_from(self.read_i16()?).ok()?;
+        let bytes = self.bytes.get(self.position..self.position + len)?;
+        self.position += len;
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::admin::MemberAssignment;
+
+    use super::decode_member_assignment;
+
+    fn encode_assignment(version: i16, assignments: &[(&str, &[i32])]) -> Vec<u8> {
+        let mut res = version.to_be_bytes().to_vec();
+        res.extend((assignments.len() as i32).to_be_bytes());
+        for (topic, partitions) in assignments {
+            res.extend((topic.len() as i16).to_be_bytes());
+            res.extend(topic.as_bytes());
+            res.extend((partitions.len() as i32).to_be_bytes());
+            for p in partitions.iter() {
+                res.extend(p.to_be_bytes());
+            }
+        }
+        // user data
+        res.extend((-1_i32).to_be_bytes());
+        res
+    }
+
+    #[test]
+    fn test_decode_member_assignment() {
+        let bytes = encode_assignment(1, &[("topic-a", &[0, 2]), ("topic-b", &[1])]);
+        let res = decode_member_assignment(&bytes).unwrap();
+        assert_eq!(
+            res,
+            vec![
+                MemberAssignment {
+                    topic: "topic-a".into(),
+                    partitions: vec![0, 2]
+                },
+                MemberAssignment {
+                    topic: "topic-b".into(),
+                    partitions: vec![1]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_empty_member_assignment() {
+        assert_eq!(decode_member_assignment(&[]), Some(vec![]));
+        assert_eq!(decode_member_assignment(&encode_assignment(0, &[])), Some(vec![]));
+    }
+
+    #[test]
+    fn test_decode_invalid_member_assignment() {
+        let bytes = encode_assignment(1, &[("topic-a", &[0, 2])]);
+        // truncated payload
+        assert_eq!(decode_member_assignment(&bytes[..bytes.len() - 10]), None);
+        assert_eq!(decode_member_assignment(&[0, 1, 0]), None);
+    }
+
+    #[test]
+    fn test_decode_invalid_array_len() {
+        // negative topics count
+        let mut bytes = 1_i16.to_be_bytes().to_vec();
+        bytes.extend((-2_i32).to_be_bytes());
+        assert_eq!(decode_member_assignment(&bytes), None);
+        // topics count larger than the payload
+        let mut bytes = 1_i16.to_be_bytes().to_vec();
+        bytes.extend(i32::MAX.to_be_bytes());
+        assert_eq!(decode_member_assignment(&bytes), None);
+        // partitions count larger than the payload
+        let mut bytes = 1_i16.to_be_bytes().to_vec();
+        bytes.extend(1_i32.to_be_bytes());
+        bytes.extend(1_i16.to_be_bytes());
+        bytes.extend(b"t");
+        bytes.extend(i32::MAX.to_be_bytes());
+        assert_eq!(decode_member_assignment(&bytes), None);
+    }
+}
@@ -6,10 +6,18 @@ use crate::core::consumer::ConsumerError;
 pub enum AdminError {
     /// topic (Name) not found
     TopicNotFound(String),
+    /// consumer group (Name) not found
+    ConsumerGroupNotFound(String),
     /// RDKafka errors
     RDKafka(String),
     // Consumer error
     ConsumerError(ConsumerError),
+    /// Invalid configuration or partition count requested
+    InvalidConfiguration(String),
+    /// Unable to read or write a file
+    IO(String),
+    /// The content of the consumer group offsets file is not valid
+    InvalidOffsetsFile(String),
 }
 
 pub type AdminResult<T> = Result<T, AdminError>;
@@ -24,6 +32,11 @@ impl From<RDKafkaErrorCode> for AdminError {
         AdminError::RDKafka(value.to_string())
     }
 }
+impl From<std::io::Error> for AdminError {
+    fn from(error: std::io::Error) -> Self {
+        AdminError::IO(error.to_string())
+    }
+}
 //todo: change with the consumer error
 impl From<ConsumerError> for AdminError {
     fn from(value: ConsumerError) -> Self {
@@ -1,6 +1,10 @@
+mod acl_admin;
 mod client;
+mod cluster_admin;
 mod consumer_admin;
+mod consumer_protocol;
 mod error;
+mod offsets_file;
 mod topic_admin;
 mod types;
 
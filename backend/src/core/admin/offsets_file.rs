@@ -0,0 +1,154 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+};
+
+use log::debug;
+
+use super::{AdminError, AdminResult, ConsumerGroupOffsets, OffsetsFileFormat, TopicPartitionOffset};
+
+const SEPARATOR: &str = ";";
+const CSV_HEADER: &str = "topic;partition;offset";
+
+pub(super) fn write_offsets_file(
+    path: &str,
+    offsets: &ConsumerGroupOffsets,
+    format: OffsetsFileFormat,
+    overwrite: bool,
+) -> AdminResult<()> {
+    debug!("Write the offsets of {} to {}", offsets.consumer_group, path);
+    let content = match format {
+        OffsetsFileFormat::Json => serde_json::to_string_pretty(offsets)
+            .map_err(|err| AdminError::InvalidOffsetsFile(format!("Unable to serialize the offsets: {err}")))?,
+        OffsetsFileFormat::Csv => {
+            let rows = offsets
+                .offsets
+                .iter()
+                .map(|o| [o.topic.clone(), o.partition_id.to_string(), o.offset.to_string()].join(SEPARATOR));
+            std::iter::once(CSV_HEADER.to_string())
+                .chain(rows)
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    };
+    let mut file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(overwrite)
+        .create_new(!overwrite)
+        .open(path)?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+/// The csv files don't include the consumer group name, an empty name is returned
+pub(super) fn read_offsets_file(path: &str, format: OffsetsFileFormat) -> AdminResult<ConsumerGroupOffsets> {
+    debug!("Read the offsets from {}", path);
+    let content = fs::read_to_string(path)?;
+    match format {
+        OffsetsFileFormat::Json => serde_json::from_str(&content)
+            .map_err(|err| AdminError::InvalidOffsetsFile(format!("Unable to parse the offsets file {path}: {err}"))),
+        OffsetsFileFormat::Csv => {
+            let offsets = content
+                .lines()
+                .enumerate()
+                .filter(|(i, line)| !line.trim().is_empty() && (*i != 0 || line.trim() != CSV_HEADER))
+                .map(|(i, line)| {
+                    parse_csv_row(line)
+                        .ok_or_else(|| AdminError::InvalidOffsetsFile(format!("Invalid row {} in {path}: {line}", i + 1)))
+                })
+                .collect::<AdminResult<Vec<_>>>()?;
+            Ok(ConsumerGroupOffsets {
+                consumer_group: String::new(),
+                offsets,
+            })
+        }
+    }
+}
+
+fn parse_csv_row(line: &str) -> Option<TopicPartitionOffset> {
+    // the topic names can't contain the separator
+    let mut fields = line.split(SEPARATOR).map(|f| f.trim());
+    let topic = fields.next().filter(|t| !t.is_empty())?;
+    let partition_id = fields.next()?.parse().ok()?;
+    let offset = fields.next()?.parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    Some(TopicPartitionOffset {
+        topic: topic.into(),
+        partition_id,
+        offset,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs};
+
+    use crate::core::admin::{ConsumerGroupOffsets, OffsetsFileFormat, TopicPartitionOffset};
+
+    use super::{read_offsets_file, write_offsets_file};
+
+    fn get_test_offsets() -> ConsumerGroupOffsets {
+        ConsumerGroupOffsets {
+            consumer_group: "test-group".into(),
+            offsets: vec![
+                TopicPartitionOffset {
+                    topic: "topic-a".into(),
+                    partition_id: 0,
+                    offset: 10,
+                },
+                TopicPartitionOffset {
+                    topic: "topic-b".into(),
+                    partition_id: 3,
+                    offset: 0,
+                },
+            ],
+        }
+    }
+
+    fn get_test_file() -> String {
+        format!("{}/{}", temp_dir().to_str().unwrap(), rand::random::<usize>())
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let test_file = get_test_file();
+        write_offsets_file(&test_file, &get_test_offsets(), OffsetsFileFormat::Json, false).unwrap();
+        let res = read_offsets_file(&test_file, OffsetsFileFormat::Json).unwrap();
+        assert_eq!(res, get_test_offsets());
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let test_file = get_test_file();
+        write_offsets_file(&test_file, &get_test_offsets(), OffsetsFileFormat::Csv, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(&test_file).unwrap(),
+            "topic;partition;offset\ntopic-a;0;10\ntopic-b;3;0"
+        );
+        let res = read_offsets_file(&test_file, OffsetsFileFormat::Csv).unwrap();
+        assert_eq!(res.consumer_group, "");
+        assert_eq!(res.offsets, get_test_offsets().offsets);
+    }
+
+    #[test]
+    fn test_write_without_overwrite_fails_if_the_file_exists() {
+        let test_file = get_test_file();
+        write_offsets_file(&test_file, &get_test_offsets(), OffsetsFileFormat::Csv, false).unwrap();
+        assert!(write_offsets_file(&test_file, &get_test_offsets(), OffsetsFileFormat::Csv, false).is_err());
+        assert!(write_offsets_file(&test_file, &get_test_offsets(), OffsetsFileFormat::Csv, true).is_ok());
+    }
+
+    #[test]
+    fn test_read_invalid_csv() {
+        let test_file = get_test_file();
+        fs::write(
+            &test_file,
+            "topic;partition;offset\ntopic-a;0;10\ntopic-b;not-a-partition;0",
+        )
+        .unwrap();
+        assert!(read_offsets_file(&test_file, OffsetsFileFormat::Csv).is_err());
+    }
+}
@@ -5,14 +5,21 @@ use crate::core::admin::error::AdminError;
 
 use super::{
     error::AdminResult,
-    types::{PartitionInfo, PartitionOffset, Topic, TopicInfo},
+    types::{
+        CreateTopicOptions, DeleteRecordsConfiguration, DeletedRecords, PartitionInfo, PartitionOffset, Topic, TopicInfo,
+    },
     KafkaAdmin, Partition,
 };
-use rdkafka::{admin::ResourceSpecifier, Offset, TopicPartitionList};
 use rdkafka::{
     admin::{AdminOptions, NewTopic, TopicReplication},
     consumer::Consumer,
 };
+use rdkafka::{
+    admin::{AlterConfig, ConfigEntry, ConfigSource, NewPartitions, ResourceSpecifier},
+    error::{KafkaError, KafkaResult},
+    types::RDKafkaErrorCode,
+    Offset, TopicPartitionList,
+};
 
 impl KafkaAdmin {
     pub async fn list_topics(&self) -> AdminResult<Vec<Topic>> {
@@ -61,11 +68,15 @@ impl KafkaAdmin {
         Ok(TopicInfo {
             name: name.to_string(),
             partitions: partitions
-                .iter()
+                .into_iter()
                 .map(|p| PartitionInfo {
                     id: p.id,
                     isr: p.isr,
                     replicas: p.replicas,
+                    leader: p.leader,
+                    replica_ids: p.replica_ids,
+                    isr_ids: p.isr_ids,
+                    under_replicated: p.under_replicated,
                 })
                 .collect(),
             configurations: self.get_topic_configuration(topic_name).await?,
@@ -96,45 +107,273 @@ impl KafkaAdmin {
         Ok(res)
     }
 
-    pub async fn create_topic(&self, name: &str, num_partitions: i32, isr: i32, compacted: bool) -> AdminResult<()> {
-        let new_topic = NewTopic {
-            name,
-            num_partitions,
-            config: vec![("cleanup.policy", if compacted { "compact" } else { "delete" })],
-            replication: TopicReplication::Fixed(isr),
+    /// Create a topic with the specified configuration entries.
+    /// If validate_only is set, the request is validated by the broker but the topic is not created
+    pub async fn create_topic_with_options(
+        &self,
+        name: &str,
+        num_partitions: i32,
+        isr: i32,
+        options: &CreateTopicOptions,
+    ) -> AdminResult<()> {
+        let CreateTopicOptions {
+            configurations,
+            validate_only,
+        } = options;
+        match self
+            .internal_create_topic(name, num_partitions, isr, configurations, *validate_only)
+            .await
+        {
+            Ok(_) => {
+                debug!("Topic created successfully");
+                Ok(())
+            }
+            Err(KafkaError::AdminOp(RDKafkaErrorCode::InvalidConfig)) => {
+                // the broker doesn't report which entry is invalid, validate them one by one to find out
+                let mut rejected = vec![];
+                if configurations.len() > 1 {
+                    for (key, value) in configurations {
+                        let entry = HashMap::from([(key.clone(), value.clone())]);
+                        if let Err(err) = self
+                            .internal_create_topic(name, num_partitions, isr, &entry, true)
+                            .await
+                        {
+                            rejected.push(format!("{key}={value} ({err})"));
+                        }
+                    }
+                }
+                if rejected.is_empty() {
+                    rejected = configurations
+                        .iter()
+                        .map(|(key, value)| format!("{key}={value}"))
+                        .collect();
+                }
+                warn!("Invalid configuration entries {:?}", rejected);
+                Err(AdminError::InvalidConfiguration(format!(
+                    "Unable to create topic {name}. The broker rejected the configuration entries: {}",
+                    rejected.join(", ")
+                )))
+            }
+            Err(KafkaError::AdminOp(err)) => {
+                warn!("{:?}", err);
+                Err(err.into())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub async fn get_topic_configuration(&self, topic_name: &str) -> AdminResult<HashMap<String, Option<String>>> {
+        let mut configurations = HashMap::<String, Option<String>>::new();
+        self.describe_topic_configuration(topic_name)
+            .await?
+            .into_iter()
+            .for_each(|c| {
+                configurations.insert(c.name, c.value);
+            });
+        Ok(configurations)
+    }
+
+    /// Update the topic configurations. A None value resets the configuration to the broker default.
+    /// The alter configs api replaces all the dynamic configurations of the topic, so the ones
+    /// not specified are retrieved from the broker and sent along with the updated ones
+    pub async fn alter_topic_configuration(
+        &self,
+        topic_name: &str,
+        configurations: &HashMap<String, Option<String>>,
+    ) -> AdminResult<()> {
+        debug!("Alter the configurations of topic {}", topic_name);
+        let current = self.describe_topic_configuration(topic_name).await?;
+        for name in configurations.keys() {
+            match current.iter().find(|c| &c.name == name) {
+                None => {
+                    return Err(AdminError::InvalidConfiguration(format!(
+                        "Unknown configuration {name} for topic {topic_name}"
+                    )))
+                }
+                Some(entry) if entry.is_read_only => {
+                    return Err(AdminError::InvalidConfiguration(format!(
+                        "The configuration {name} of topic {topic_name} is read only"
+                    )))
+                }
+                _ => {}
+            }
+        }
+        let mut entries: HashMap<&str, &str> = current
+            .iter()
+            .filter(|c| c.source == ConfigSource::DynamicTopic)
+            .filter_map(|c| c.value.as_deref().map(|v| (c.name.as_str(), v)))
+            .collect();
+        for (name, value) in configurations {
+            match value {
+                Some(value) => entries.insert(name, value),
+                None => entries.remove(name.as_str()),
+            };
+        }
+        let alter_config = AlterConfig {
+            specifier: ResourceSpecifier::Topic(topic_name),
+            entries,
         };
         let res = self
             .admin_client
-            .create_topics(vec![&new_topic], &AdminOptions::default())
+            .alter_configs([&alter_config], &AdminOptions::default())
             .await?;
-        let res = res
-            .first()
-            .ok_or_else(|| AdminError::RDKafka("Invalid result from create topic api call.".into()))?;
-        match res {
-            Ok(_) => {
-                debug!("Topic created successfully");
-                Ok(())
+        match res.first() {
+            Some(Ok(_)) => Ok(()),
+            Some(Err((_, err))) => {
+                warn!("{:?}", err);
+                Err((*err).into())
             }
-            Err(err) => {
+            None => Err(AdminError::RDKafka(
+                "Invalid result from alter configs api call.".into(),
+            )),
+        }
+    }
+
+    /// Increase the number of partitions of the topic to `partition_count`
+    pub async fn create_partitions(&self, topic_name: &str, partition_count: usize) -> AdminResult<()> {
+        debug!("Increase the partitions of topic {} to {}", topic_name, partition_count);
+        let current_count = self.get_topic(topic_name)?.partitions.len();
+        if partition_count <= current_count {
+            return Err(AdminError::InvalidConfiguration(format!(
+                "The partition count can only be increased. Topic {topic_name} has {current_count} partitions"
+            )));
+        }
+        let res = self
+            .admin_client
+            .create_partitions(
+                [&NewPartitions::new(topic_name, partition_count)],
+                &AdminOptions::default(),
+            )
+            .await?;
+        {
+            // delete cache of topics/partitions map
+            *self.all_topic_partition_list.write().await = TopicPartitionList::new();
+        }
+        match res.first() {
+            Some(Ok(_)) => Ok(()),
+            Some(Err(err)) => {
                 warn!("{:?}", err);
                 Err(err.1.into())
             }
+            None => Err(AdminError::RDKafka(
+                "Invalid result from create partitions api call.".into(),
+            )),
         }
     }
 
-    pub async fn get_topic_configuration(&self, topic_name: &str) -> AdminResult<HashMap<String, Option<String>>> {
+    /// Compute the records that would be deleted from each partition by `delete_records`
+    pub async fn preview_delete_records(
+        &self,
+        topic_name: &str,
+        configuration: &DeleteRecordsConfiguration,
+    ) -> AdminResult<Vec<DeletedRecords>> {
+        let topic = self.get_topic(topic_name)?;
+        let timestamp_offsets = match configuration {
+            DeleteRecordsConfiguration::Timestamp(timestamp) => {
+                let mut tp = TopicPartitionList::new();
+                for p in &topic.partitions {
+                    tp.add_partition_offset(topic_name, p.id, Offset::Offset(*timestamp))?;
+                }
+                Some(self.consumer.offsets_for_times(tp, self.timeout)?)
+            }
+            _ => None,
+        };
+        let mut res = vec![];
+        for p in topic.partitions {
+            let (low, high) = self.consumer.fetch_watermarks(topic_name, p.id, self.timeout)?;
+            let before_offset = match configuration {
+                DeleteRecordsConfiguration::All => Some(high),
+                DeleteRecordsConfiguration::Offsets(offsets) => offsets.get(&p.id).copied(),
+                DeleteRecordsConfiguration::Timestamp(_) => {
+                    match timestamp_offsets
+                        .as_ref()
+                        .and_then(|tp| tp.find_partition(topic_name, p.id))
+                    {
+                        Some(tpo) => {
+                            tpo.error()?;
+                            match tpo.offset() {
+                                Offset::Offset(offset) => Some(offset),
+                                // no records produced after the timestamp
+                                Offset::End => Some(high),
+                                _ => None,
+                            }
+                        }
+                        None => None,
+                    }
+                }
+            };
+            if let Some(before_offset) = before_offset {
+                let before_offset = before_offset.clamp(low, high);
+                res.push(DeletedRecords {
+                    partition_id: p.id,
+                    low_offset: low,
+                    before_offset,
+                    records_count: before_offset - low,
+                });
+            }
+        }
+        Ok(res)
+    }
+
+    /// Truncate the partitions of the topic, deleting the records before the configured offsets
+    pub async fn delete_records(
+        &self,
+        topic_name: &str,
+        configuration: &DeleteRecordsConfiguration,
+    ) -> AdminResult<Vec<DeletedRecords>> {
+        let deleted_records = self.preview_delete_records(topic_name, configuration).await?;
+        let mut tp = TopicPartitionList::new();
+        for d in deleted_records.iter().filter(|d| d.records_count > 0) {
+            tp.add_partition_offset(topic_name, d.partition_id, Offset::Offset(d.before_offset))?;
+        }
+        if tp.count() > 0 {
+            debug!("Delete records from topic {}: {:?}", topic_name, deleted_records);
+            let res = self.admin_client.delete_records(&tp, &AdminOptions::default()).await?;
+            for tpo in res.elements() {
+                tpo.error()?;
+            }
+        }
+        Ok(deleted_records)
+    }
+
+    async fn describe_topic_configuration(&self, topic_name: &str) -> AdminResult<Vec<ConfigEntry>> {
         debug!("Retrieving the topic configurations");
         let responses = self
             .admin_client
             .describe_configs([&ResourceSpecifier::Topic(topic_name)], &AdminOptions::default())
             .await?;
-        let mut configurations = HashMap::<String, Option<String>>::new();
-        if let Some(Ok(topic_config)) = responses.first() {
-            topic_config.entries.iter().for_each(|c| {
-                configurations.insert(c.name.clone(), c.value.as_ref().cloned());
-            });
+        match responses.into_iter().next() {
+            Some(Ok(topic_config)) => Ok(topic_config.entries),
+            Some(Err(err)) => Err(err.into()),
+            None => Err(AdminError::TopicNotFound(topic_name.to_string())),
+        }
+    }
+
+    async fn internal_create_topic(
+        &self,
+        name: &str,
+        num_partitions: i32,
+        isr: i32,
+        configurations: &HashMap<String, String>,
+        validate_only: bool,
+    ) -> KafkaResult<()> {
+        let new_topic = NewTopic {
+            name,
+            num_partitions,
+            config: configurations.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect(),
+            replication: TopicReplication::Fixed(isr),
+        };
+        let res = self
+            .admin_client
+            .create_topics(vec![&new_topic], &AdminOptions::new().validate_only(validate_only))
+            .await?;
+        match res.first() {
+            Some(Ok(_)) => Ok(()),
+            Some(Err((_, err))) => Err(KafkaError::AdminOp(*err)),
+            None => Err(KafkaError::AdminOpCreation(
+                "Invalid result from create topic api call.".into(),
+            )),
         }
-        Ok(configurations)
     }
 
     fn internal_list_topics(&self, topic: Option<&str>) -> AdminResult<Vec<Topic>> {
@@ -152,6 +391,10 @@ impl KafkaAdmin {
                         id: m.id(),
                         isr: m.isr().len(),
                         replicas: m.replicas().len(),
+                        leader: if m.leader() < 0 { None } else { Some(m.leader()) },
+                        replica_ids: m.replicas().into(),
+                        isr_ids: m.isr().into(),
+                        under_replicated: m.isr().len() < m.replicas().len(),
                     })
                     .collect(),
             })
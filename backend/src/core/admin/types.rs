@@ -13,6 +13,15 @@ pub struct Partition {
     pub id: i32,
     pub isr: usize,
     pub replicas: usize,
+    /// Broker id of the partition leader. None if the partition is offline
+    pub leader: Option<i32>,
+    #[serde(rename = "replicaIds")]
+    pub replica_ids: Vec<i32>,
+    #[serde(rename = "isrIds")]
+    pub isr_ids: Vec<i32>,
+    /// true if some replicas are not in sync
+    #[serde(rename = "underReplicated")]
+    pub under_replicated: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -22,20 +31,194 @@ pub struct TopicInfo {
     pub configurations: HashMap<String, Option<String>>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateTopicOptions {
+    /// Topic configuration entries
+    #[serde(default)]
+    pub configurations: HashMap<String, String>,
+    /// Validate the request with the broker without creating the topic
+    #[serde(rename = "validateOnly", default)]
+    pub validate_only: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClusterInfo {
+    /// Id of the controller broker. None if it can't be retrieved
+    #[serde(rename = "controllerId")]
+    pub controller_id: Option<i32>,
+    pub brokers: Vec<BrokerInfo>,
+    #[serde(rename = "topicsCount")]
+    pub topics_count: usize,
+    #[serde(rename = "partitionsCount")]
+    pub partitions_count: usize,
+    /// Partitions with less in sync replicas than replicas
+    #[serde(rename = "underReplicatedPartitions")]
+    pub under_replicated_partitions: usize,
+    /// Partitions without a leader
+    #[serde(rename = "offlinePartitions")]
+    pub offline_partitions: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BrokerInfo {
+    pub id: i32,
+    pub host: String,
+    pub port: i32,
+    pub rack: Option<String>,
+    #[serde(rename = "isController")]
+    pub is_controller: bool,
+}
+
+/// Records to delete from the beginning of each partition of a topic
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum DeleteRecordsConfiguration {
+    /// Delete all the records
+    All,
+    /// Delete the records produced before the timestamp, time in ms
+    Timestamp(i64),
+    /// Delete the records before the offset (exclusive) for each partition id.
+    /// The partitions not specified are not affected
+    Offsets(HashMap<i32, i64>),
+}
+
+/// New offset to commit for each partition of a topic in a consumer group
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum OffsetResetStrategy {
+    /// First offset available (low watermark)
+    Earliest,
+    /// Last offset (high watermark)
+    Latest,
+    /// Explicit offset for each partition id. The partitions not specified are not affected
+    Offsets(HashMap<i32, i64>),
+    /// Move the committed offset by N records, backwards if negative
+    ShiftBy(i64),
+    /// First offset with a timestamp greater or equal than the timestamp, time in ms
+    Timestamp(i64),
+    /// First offset with a timestamp greater or equal than the timestamp of each partition id, time in ms.
+    /// The partitions not specified are not affected
+    Timestamps(HashMap<i32, i64>),
+    /// Commit the current offset again
+    Current,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OffsetReset {
+    pub topic: String,
+    #[serde(rename = "partitionId")]
+    pub partition_id: i32,
+    /// Offset committed before the reset. None if the consumer group never committed on the partition
+    #[serde(rename = "currentOffset")]
+    pub current_offset: Option<i64>,
+    #[serde(rename = "newOffset")]
+    pub new_offset: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DeletedRecords {
+    #[serde(rename = "partitionId")]
+    pub partition_id: i32,
+    /// First offset available in the partition before the deletion
+    #[serde(rename = "lowOffset")]
+    pub low_offset: i64,
+    /// The records before this offset (exclusive) are deleted
+    #[serde(rename = "beforeOffset")]
+    pub before_offset: i64,
+    /// Number of records deleted. It is an upper bound for compacted and transactional topics
+    #[serde(rename = "recordsCount")]
+    pub records_count: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PartitionInfo {
     pub id: i32,
     pub isr: usize,
     pub replicas: usize,
+    /// Broker id of the partition leader. None if the partition is offline
+    pub leader: Option<i32>,
+    #[serde(rename = "replicaIds")]
+    pub replica_ids: Vec<i32>,
+    #[serde(rename = "isrIds")]
+    pub isr_ids: Vec<i32>,
+    /// true if some replicas are not in sync
+    #[serde(rename = "underReplicated")]
+    pub under_replicated: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ConsumerGroupInfo {
     pub name: String,
+    pub state: String,
+    /// Protocol type of the group, "consumer" for the kafka consumers
+    #[serde(rename = "protocolType")]
+    pub protocol_type: String,
+    /// Partition assignor used by the group (e.g. range, roundrobin)
+    pub protocol: String,
+    pub members: Vec<ConsumerGroupMember>,
     pub offsets: Vec<TopicPartitionOffset>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConsumerGroupMember {
+    pub id: String,
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+    pub host: String,
+    /// Partitions assigned to the member. Empty if the group doesn't use the consumer protocol
+    pub assignments: Vec<MemberAssignment>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MemberAssignment {
+    pub topic: String,
+    pub partitions: Vec<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConsumerGroupLag {
+    pub name: String,
+    /// Sum of the lag of all the partitions
+    #[serde(rename = "totalLag")]
+    pub total_lag: i64,
+    /// Highest time lag across the partitions in ms
+    #[serde(rename = "maxTimeLagMs")]
+    pub max_time_lag_ms: Option<i64>,
+    pub partitions: Vec<PartitionLag>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PartitionLag {
+    pub topic: String,
+    #[serde(rename = "partitionId")]
+    pub partition_id: i32,
+    #[serde(rename = "committedOffset")]
+    pub committed_offset: i64,
+    /// High watermark of the partition
+    #[serde(rename = "endOffset")]
+    pub end_offset: i64,
+    /// Number of records to consume to reach the end of the partition
+    pub lag: i64,
+    /// Time in ms elapsed since the record at the committed offset was produced.
+    /// None if there is no lag or the record can't be retrieved
+    #[serde(rename = "timeLagMs")]
+    pub time_lag_ms: Option<i64>,
+}
+
+/// Snapshot of the offsets committed by a consumer group, exported to file
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerGroupOffsets {
+    #[serde(rename = "consumerGroup")]
+    pub consumer_group: String,
+    pub offsets: Vec<TopicPartitionOffset>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum OffsetsFileFormat {
+    Json,
+    /// One row per partition with topic;partition;offset
+    Csv,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct TopicPartitionOffset {
     pub topic: String,
     pub partition_id: i32,
@@ -48,3 +231,86 @@ pub struct PartitionOffset {
     pub partition_id: i32,
     pub offset: i64,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AclResourceType {
+    Unknown,
+    #[default]
+    Any,
+    Topic,
+    Group,
+    Cluster,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AclPatternType {
+    Unknown,
+    #[default]
+    Any,
+    /// Filter only. Match the bindings that affect the resource name: literal, wildcard and prefixed
+    Match,
+    Literal,
+    Prefixed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AclOperation {
+    Unknown,
+    #[default]
+    Any,
+    All,
+    Read,
+    Write,
+    Create,
+    Delete,
+    Alter,
+    Describe,
+    ClusterAction,
+    DescribeConfigs,
+    AlterConfigs,
+    IdempotentWrite,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AclPermissionType {
+    Unknown,
+    #[default]
+    Any,
+    Deny,
+    Allow,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AclBinding {
+    #[serde(rename = "resourceType")]
+    pub resource_type: AclResourceType,
+    /// Name of the resource, "*" for all the resources of the type
+    #[serde(rename = "resourceName")]
+    pub resource_name: String,
+    #[serde(rename = "patternType")]
+    pub pattern_type: AclPatternType,
+    /// Principal in the form "User:name"
+    pub principal: String,
+    /// Host allowed or denied, "*" for all the hosts
+    pub host: String,
+    pub operation: AclOperation,
+    #[serde(rename = "permissionType")]
+    pub permission_type: AclPermissionType,
+}
+
+/// Filter of the ACL bindings. The fields not specified match any value
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AclBindingFilter {
+    #[serde(rename = "resourceType", default)]
+    pub resource_type: AclResourceType,
+    #[serde(rename = "resourceName")]
+    pub resource_name: Option<String>,
+    #[serde(rename = "patternType", default)]
+    pub pattern_type: AclPatternType,
+    pub principal: Option<String>,
+    pub host: Option<String>,
+    #[serde(default)]
+    pub operation: AclOperation,
+    #[serde(rename = "permissionType", default)]
+    pub permission_type: AclPermissionType,
+}
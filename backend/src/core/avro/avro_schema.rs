@@ -1,14 +1,19 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use apache_avro::{
     schema::{DecimalSchema, EnumSchema, FixedSchema, Name, RecordSchema, ResolvedSchema},
     Schema,
 };
+use serde::Serialize;
+use serde_json::Value as JsonValue;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct RecordField {
     pub name: String,
     pub schema: AvroSchema,
+    pub default: Option<JsonValue>,
+    /// Previous names of the field
+    pub aliases: Vec<String>,
 }
 
 /// Avro schema without references
@@ -96,16 +101,264 @@ impl AvroSchema {
     }
 }
 
+/// Structural change between two versions of a schema
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// Dot separated path of the changed element, starting from the name of the root schema
+    pub path: String,
+    pub kind: SchemaChangeKind,
+    /// The new schema can read the data written with the old one
+    #[serde(rename = "backwardCompatible")]
+    pub backward_compatible: bool,
+    /// The old schema can read the data written with the new one
+    #[serde(rename = "forwardCompatible")]
+    pub forward_compatible: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChangeKind {
+    FieldAdded,
+    FieldRemoved,
+    /// The new field declares the old name as alias
+    FieldRenamed {
+        from: String,
+    },
+    TypeChanged {
+        from: String,
+        to: String,
+    },
+    DefaultChanged {
+        from: Option<JsonValue>,
+        to: Option<JsonValue>,
+    },
+    EnumSymbolsAdded {
+        symbols: Vec<String>,
+    },
+    EnumSymbolsRemoved {
+        symbols: Vec<String>,
+    },
+}
+
+impl AvroSchema {
+    /// List the changes from this schema to the new one
+    pub fn diff(&self, new: &AvroSchema) -> Vec<SchemaChange> {
+        let mut changes = vec![];
+        diff_schemas(self, new, &self.fqn(), &mut changes);
+        changes
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            AvroSchema::Array(s) => format!("array<{}>", s.describe()),
+            AvroSchema::Map(s) => format!("map<{}>", s.describe()),
+            AvroSchema::Union(variants) => format!(
+                "union<{}>",
+                variants.iter().map(|v| v.describe()).collect::<Vec<_>>().join(", ")
+            ),
+            AvroSchema::Fixed { size, .. } => format!("{}(fixed {size})", self.fqn()),
+            AvroSchema::Decimal { precision, scale } => format!("decimal({precision}, {scale})"),
+            _ => self.fqn(),
+        }
+    }
+
+    /// Primitive type used to encode the logical types
+    fn physical(&self) -> AvroSchema {
+        match self {
+            AvroSchema::Date | AvroSchema::TimeMillis => AvroSchema::Int,
+            AvroSchema::TimeMicros
+            | AvroSchema::TimestampMillis
+            | AvroSchema::TimestampMicros
+            | AvroSchema::TimestampNanos
+            | AvroSchema::LocalTimestampMillis
+            | AvroSchema::LocalTimestampMicros
+            | AvroSchema::LocalTimestampNanos => AvroSchema::Long,
+            AvroSchema::Uuid => AvroSchema::String,
+            AvroSchema::BigDecimal => AvroSchema::Bytes,
+            _ => self.clone(),
+        }
+    }
+
+    /// Check if the data written with the writer schema can be read with this schema.
+    /// The fields of the records are not compared, they are diffed separately
+    fn can_read(&self, writer: &AvroSchema) -> bool {
+        use AvroSchema::*;
+        match (self.physical(), writer.physical()) {
+            (_, Union(w)) => w.iter().all(|w| self.can_read(w)),
+            (Union(r), _) => r.iter().any(|r| r.can_read(writer)),
+            (Long, Int) | (Float, Int | Long) | (Double, Int | Long | Float) | (String, Bytes) | (Bytes, String) => true,
+            (Array(r), Array(w)) | (Map(r), Map(w)) => r.can_read(&w),
+            (Record { name: r, .. }, Record { name: w, .. }) | (Enum { name: r, .. }, Enum { name: w, .. }) => r == w,
+            (r, w) => r == w,
+        }
+    }
+}
+
+fn diff_schemas(old: &AvroSchema, new: &AvroSchema, path: &str, changes: &mut Vec<SchemaChange>) {
+    let type_change = || SchemaChange {
+        path: path.into(),
+        kind: SchemaChangeKind::TypeChanged {
+            from: old.describe(),
+            to: new.describe(),
+        },
+        backward_compatible: new.can_read(old),
+        forward_compatible: old.can_read(new),
+    };
+    match (old, new) {
+        (
+            AvroSchema::Record {
+                name: old_name,
+                fields: old_fields,
+                ..
+            },
+            AvroSchema::Record {
+                name: new_name,
+                fields: new_fields,
+                ..
+            },
+        ) => {
+            if old_name != new_name {
+                changes.push(type_change());
+            }
+            diff_fields(old_fields, new_fields, path, changes);
+        }
+        (
+            AvroSchema::Enum {
+                name: old_name,
+                symbols: old_symbols,
+            },
+            AvroSchema::Enum {
+                name: new_name,
+                symbols: new_symbols,
+            },
+        ) => {
+            if old_name != new_name {
+                changes.push(type_change());
+            }
+            let added: Vec<_> = new_symbols
+                .iter()
+                .filter(|s| !old_symbols.contains(s))
+                .cloned()
+                .collect();
+            let removed: Vec<_> = old_symbols
+                .iter()
+                .filter(|s| !new_symbols.contains(s))
+                .cloned()
+                .collect();
+            if !added.is_empty() {
+                changes.push(SchemaChange {
+                    path: path.into(),
+                    kind: SchemaChangeKind::EnumSymbolsAdded { symbols: added },
+                    backward_compatible: true,
+                    forward_compatible: false,
+                });
+            }
+            if !removed.is_empty() {
+                changes.push(SchemaChange {
+                    path: path.into(),
+                    kind: SchemaChangeKind::EnumSymbolsRemoved { symbols: removed },
+                    backward_compatible: false,
+                    forward_compatible: true,
+                });
+            }
+        }
+        (AvroSchema::Array(old_items), AvroSchema::Array(new_items)) => diff_schemas(old_items, new_items, path, changes),
+        (AvroSchema::Map(old_values), AvroSchema::Map(new_values)) => diff_schemas(old_values, new_values, path, changes),
+        (AvroSchema::Union(old_variants), AvroSchema::Union(new_variants)) => {
+            let fqns = |variants: &[AvroSchema]| variants.iter().map(|v| v.fqn()).collect::<HashSet<_>>();
+            if fqns(old_variants) != fqns(new_variants) {
+                changes.push(type_change());
+            }
+            // the nested changes of the variants available in both the versions
+            for old_variant in old_variants {
+                if let Some(new_variant) = new_variants.iter().find(|v| v.fqn() == old_variant.fqn()) {
+                    diff_schemas(old_variant, new_variant, path, changes);
+                }
+            }
+        }
+        _ if old != new => changes.push(type_change()),
+        _ => {}
+    }
+}
+
+fn diff_fields(old_fields: &[RecordField], new_fields: &[RecordField], path: &str, changes: &mut Vec<SchemaChange>) {
+    for new_field in new_fields {
+        let field_path = format!("{path}.{}", new_field.name);
+        let same_name = old_fields.iter().find(|f| f.name == new_field.name);
+        let renamed_from = old_fields
+            .iter()
+            .find(|f| new_field.aliases.contains(&f.name) && !new_fields.iter().any(|n| n.name == f.name));
+        match (same_name, renamed_from) {
+            (Some(old_field), _) => {
+                if old_field.default != new_field.default {
+                    changes.push(SchemaChange {
+                        path: field_path.clone(),
+                        kind: SchemaChangeKind::DefaultChanged {
+                            from: old_field.default.clone(),
+                            to: new_field.default.clone(),
+                        },
+                        backward_compatible: true,
+                        forward_compatible: true,
+                    });
+                }
+                diff_schemas(&old_field.schema, &new_field.schema, &field_path, changes);
+            }
+            (None, Some(old_field)) => {
+                // the old readers don't know the new name, they can only use the default
+                changes.push(SchemaChange {
+                    path: field_path.clone(),
+                    kind: SchemaChangeKind::FieldRenamed {
+                        from: old_field.name.clone(),
+                    },
+                    backward_compatible: true,
+                    forward_compatible: old_field.default.is_some(),
+                });
+                diff_schemas(&old_field.schema, &new_field.schema, &field_path, changes);
+            }
+            (None, None) => changes.push(SchemaChange {
+                path: field_path,
+                kind: SchemaChangeKind::FieldAdded,
+                backward_compatible: new_field.default.is_some(),
+                forward_compatible: true,
+            }),
+        }
+    }
+    old_fields
+        .iter()
+        .filter(|f| {
+            !new_fields
+                .iter()
+                .any(|n| n.name == f.name || n.aliases.contains(&f.name))
+        })
+        .for_each(|old_field| {
+            changes.push(SchemaChange {
+                path: format!("{path}.{}", old_field.name),
+                kind: SchemaChangeKind::FieldRemoved,
+                backward_compatible: true,
+                forward_compatible: old_field.default.is_some(),
+            })
+        });
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct ResolvedAvroSchema {
     pub id: i32,
     pub schema: AvroSchema,
     pub inner_schema: Schema,
+    /// Schemas of the types referenced by the inner schema and defined in other subjects
+    pub referenced_schemas: Vec<Schema>,
 }
 
 impl ResolvedAvroSchema {
-    pub fn from(id: i32, schema: &Schema) -> Self {
-        let resolved_schema = ResolvedSchema::try_from(schema).unwrap();
+    /// The referenced schemas define the named types used by the schema and declared in other subjects
+    pub fn from(id: i32, schema: &Schema, referenced_schemas: Vec<Schema>) -> Self {
+        let referenced_schemas = sort_by_dependencies(referenced_schemas);
+        let resolved_schema = ResolvedSchema::try_from(
+            referenced_schemas
+                .iter()
+                .chain(std::iter::once(schema))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
         let references = resolved_schema.get_names();
 
         fn map(s: &Schema, parent_ns: &Option<String>, references: &HashMap<Name, &Schema>) -> AvroSchema {
@@ -144,6 +397,8 @@ impl ResolvedAvroSchema {
                         .iter()
                         .map(|i| RecordField {
                             name: i.name.clone(),
+                            default: i.default.clone(),
+                            aliases: i.aliases.clone().unwrap_or_default(),
                             schema: map(
                                 &i.schema,
                                 &name.namespace.clone().or_else(|| parent_ns.clone()),
@@ -178,24 +433,81 @@ impl ResolvedAvroSchema {
             id,
             schema: map(schema, &None, references),
             inner_schema: schema.clone(),
+            referenced_schemas,
         }
     }
+
+    /// The referenced schemas followed by the inner schema, required to encode and decode the records
+    pub fn schemata(&self) -> Vec<&Schema> {
+        self.referenced_schemas
+            .iter()
+            .chain(std::iter::once(&self.inner_schema))
+            .collect()
+    }
+}
+
+/// Sort the schemas so that each named type is defined before being referenced,
+/// the order required by apache avro to resolve a list of schemas
+fn sort_by_dependencies(mut schemas: Vec<Schema>) -> Vec<Schema> {
+    fn collect_names(s: &Schema, ns: &Option<String>, defined: &mut HashSet<Name>, referenced: &mut HashSet<Name>) {
+        match s {
+            Schema::Array(s) => collect_names(&s.items, ns, defined, referenced),
+            Schema::Map(s) => collect_names(&s.types, ns, defined, referenced),
+            Schema::Union(s) => s
+                .variants()
+                .iter()
+                .for_each(|s| collect_names(s, ns, defined, referenced)),
+            Schema::Record(RecordSchema { name, fields, .. }) => {
+                let fqn = name.fully_qualified_name(ns);
+                fields
+                    .iter()
+                    .for_each(|f| collect_names(&f.schema, &fqn.namespace, defined, referenced));
+                defined.insert(fqn);
+            }
+            Schema::Enum(EnumSchema { name, .. }) | Schema::Fixed(FixedSchema { name, .. }) => {
+                defined.insert(name.fully_qualified_name(ns));
+            }
+            Schema::Ref { name } => {
+                referenced.insert(name.fully_qualified_name(ns));
+            }
+            _ => {}
+        }
+    }
+    let mut known = HashSet::new();
+    let mut sorted = Vec::with_capacity(schemas.len());
+    while !schemas.is_empty() {
+        let next = schemas.iter().position(|s| {
+            let (mut defined, mut referenced) = (HashSet::new(), HashSet::new());
+            collect_names(s, &None, &mut defined, &mut referenced);
+            referenced.iter().all(|n| known.contains(n) || defined.contains(n))
+        });
+        // with circular references there is no valid order, keep the remaining schemas as they are
+        let Some(next) = next else {
+            sorted.append(&mut schemas);
+            break;
+        };
+        let schema = schemas.remove(next);
+        collect_names(&schema, &None, &mut known, &mut HashSet::new());
+        sorted.push(schema);
+    }
+    sorted
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::ResolvedAvroSchema;
+    use super::{AvroSchema, ResolvedAvroSchema, SchemaChange, SchemaChangeKind};
     use std::fs;
 
     use apache_avro::Schema;
+    use serde_json::json;
 
     #[test]
     fn test_parse_schema_with_enum() {
         let test_schema = fs::read_to_string("src/core/avro/test_schemas/nested_refs.json").unwrap();
         let schema = Schema::parse_str(&test_schema).unwrap();
         // should not panic
-        ResolvedAvroSchema::from(123, &schema);
+        ResolvedAvroSchema::from(123, &schema, vec![]);
     }
 
     #[test]
@@ -203,6 +515,155 @@ mod tests {
         let test_schema = fs::read_to_string("src/core/avro/test_schemas/multiple_refs.json").unwrap();
         let schema = Schema::parse_str(&test_schema).unwrap();
         // should not panic
-        ResolvedAvroSchema::from(123, &schema);
+        ResolvedAvroSchema::from(123, &schema, vec![]);
+    }
+
+    #[test]
+    fn test_parse_schema_with_schema_references() {
+        let address = r#"{"type": "record", "name": "Address", "namespace": "com.example",
+            "fields": [{"name": "street", "type": "string"}]}"#;
+        let user = r#"{"type": "record", "name": "User", "namespace": "com.example",
+            "fields": [{"name": "address", "type": "com.example.Address"}]}"#;
+        let (schema, references) = Schema::parse_str_with_list(user, [address]).unwrap();
+        let res = ResolvedAvroSchema::from(123, &schema, references);
+        let AvroSchema::Record { fields, .. } = &res.schema else {
+            panic!("Expected a record, received {:?}", res.schema)
+        };
+        assert!(matches!(&fields[0].schema, AvroSchema::Record { name, .. } if name.name == "Address"));
+        assert_eq!(res.schemata().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_schema_with_transitive_schema_references() {
+        let country = r#"{"type": "enum", "name": "Country", "namespace": "com.example", "symbols": ["IT", "UK"]}"#;
+        let address = r#"{"type": "record", "name": "Address", "namespace": "com.example",
+            "fields": [{"name": "country", "type": "com.example.Country"}]}"#;
+        let user = r#"{"type": "record", "name": "User", "namespace": "com.example",
+            "fields": [{"name": "address", "type": "com.example.Address"}]}"#;
+        // the address schema references the country defined after it
+        let (schema, references) = Schema::parse_str_with_list(user, [address, country]).unwrap();
+        let res = ResolvedAvroSchema::from(123, &schema, references);
+        assert!(matches!(&res.referenced_schemas[0], Schema::Enum(_)));
+        assert_eq!(res.schemata().len(), 3);
+    }
+
+    fn diff(old: &str, new: &str) -> Vec<SchemaChange> {
+        let old = ResolvedAvroSchema::from(1, &Schema::parse_str(old).unwrap(), vec![]);
+        let new = ResolvedAvroSchema::from(2, &Schema::parse_str(new).unwrap(), vec![]);
+        old.schema.diff(&new.schema)
+    }
+
+    fn change(path: &str, kind: SchemaChangeKind, backward: bool, forward: bool) -> SchemaChange {
+        SchemaChange {
+            path: path.into(),
+            kind,
+            backward_compatible: backward,
+            forward_compatible: forward,
+        }
+    }
+
+    #[test]
+    fn test_diff_identical_schemas() {
+        let test_schema = fs::read_to_string("src/core/avro/test_schemas/nested_refs.json").unwrap();
+        assert_eq!(diff(&test_schema, &test_schema), vec![]);
+    }
+
+    #[test]
+    fn test_diff_fields() {
+        let old = r#"{"type": "record", "name": "User", "namespace": "com.example", "fields": [
+            {"name": "id", "type": "int"},
+            {"name": "surname", "type": "string", "default": ""},
+            {"name": "email", "type": "string"},
+            {"name": "age", "type": "int", "default": 0}
+        ]}"#;
+        let new = r#"{"type": "record", "name": "User", "namespace": "com.example", "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "last_name", "type": "string", "aliases": ["surname"]},
+            {"name": "age", "type": "int", "default": 18},
+            {"name": "phone", "type": ["null", "string"], "default": null},
+            {"name": "address", "type": "string"}
+        ]}"#;
+        assert_eq!(
+            diff(old, new),
+            vec![
+                change(
+                    "com.example.User.id",
+                    SchemaChangeKind::TypeChanged {
+                        from: "int".into(),
+                        to: "long".into()
+                    },
+                    true,
+                    false
+                ),
+                change(
+                    "com.example.User.last_name",
+                    SchemaChangeKind::FieldRenamed { from: "surname".into() },
+                    true,
+                    true
+                ),
+                change(
+                    "com.example.User.age",
+                    SchemaChangeKind::DefaultChanged {
+                        from: Some(json!(0)),
+                        to: Some(json!(18))
+                    },
+                    true,
+                    true
+                ),
+                change("com.example.User.phone", SchemaChangeKind::FieldAdded, true, true),
+                change("com.example.User.address", SchemaChangeKind::FieldAdded, false, true),
+                change("com.example.User.email", SchemaChangeKind::FieldRemoved, true, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_nested_types() {
+        let old = r#"{"type": "record", "name": "Order", "fields": [
+            {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["NEW", "SHIPPED", "LOST"]}},
+            {"name": "items", "type": {"type": "array", "items": {"type": "record", "name": "Item", "fields": [
+                {"name": "sku", "type": "string"}
+            ]}}},
+            {"name": "note", "type": "string"}
+        ]}"#;
+        let new = r#"{"type": "record", "name": "Order", "fields": [
+            {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["NEW", "SHIPPED", "DELIVERED"]}},
+            {"name": "items", "type": {"type": "array", "items": {"type": "record", "name": "Item", "fields": [
+                {"name": "sku", "type": "string"},
+                {"name": "quantity", "type": "int", "default": 1}
+            ]}}},
+            {"name": "note", "type": ["null", "string"]}
+        ]}"#;
+        assert_eq!(
+            diff(old, new),
+            vec![
+                change(
+                    "Order.status",
+                    SchemaChangeKind::EnumSymbolsAdded {
+                        symbols: vec!["DELIVERED".into()]
+                    },
+                    true,
+                    false
+                ),
+                change(
+                    "Order.status",
+                    SchemaChangeKind::EnumSymbolsRemoved {
+                        symbols: vec!["LOST".into()]
+                    },
+                    false,
+                    true
+                ),
+                change("Order.items.quantity", SchemaChangeKind::FieldAdded, true, true),
+                change(
+                    "Order.note",
+                    SchemaChangeKind::TypeChanged {
+                        from: "string".into(),
+                        to: "union<null, string>".into()
+                    },
+                    true,
+                    false
+                ),
+            ]
+        );
     }
 }
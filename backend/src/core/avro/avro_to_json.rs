@@ -5,7 +5,7 @@ use super::{
     helpers::get_schema_id_from_record_header,
     schema_provider::SchemaProvider,
 };
-use apache_avro::{from_avro_datum, types::Value as AvroValue};
+use apache_avro::{from_avro_datum_schemata, types::Value as AvroValue};
 use num_bigint::BigInt;
 use rust_decimal::Decimal;
 use serde_json::{json, Map, Value as JsonValue};
@@ -20,7 +20,7 @@ impl<S: SchemaProvider> AvroParser<S> {
         let mut data = Cursor::new(&raw[5..]);
 
         // parse the avro record into an AvroValue
-        let record = from_avro_datum(&schema.inner_schema, &mut data, None)
+        let record = from_avro_datum_schemata(&schema.inner_schema, schema.schemata(), &mut data, None)
             .map_err(|err| AvroError::ParseAvroValue(err.to_string()))?;
         let json = map(&record, &schema.schema)?;
         let res = serde_json::to_string(&json).map_err(|err| AvroError::ParseJsonValue(err.to_string()))?;
@@ -136,6 +136,7 @@ mod tests {
             Ok(ResolvedAvroSchema::from(
                 123,
                 &ApacheAvroSchema::parse_str(&self.schema).unwrap(),
+                vec![],
             ))
         }
         async fn get_schema_by_name(&self, _name: &str) -> AvroResult<ResolvedAvroSchema> {
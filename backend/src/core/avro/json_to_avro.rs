@@ -1,6 +1,6 @@
 use std::{collections::HashMap, str::FromStr};
 
-use apache_avro::{to_avro_datum, types::Value as AvroValue};
+use apache_avro::{to_avro_datum_schemata, types::Value as AvroValue};
 use log::debug;
 use num_bigint::BigInt;
 use uuid::Uuid;
@@ -27,8 +27,8 @@ impl<S: SchemaProvider> AvroParser<S> {
         let mut res = build_record_header(schema.id);
         let avro_value = json_to_avro_map(&json_value, &schema.schema)?;
         debug!("Parsing: {:?}\n\tUsing schema: {:?}", avro_value, &schema.schema);
-        let mut avro_record =
-            to_avro_datum(&schema.inner_schema, avro_value).map_err(|err| AvroError::ParseAvroValue(err.to_string()))?;
+        let mut avro_record = to_avro_datum_schemata(&schema.inner_schema, schema.schemata(), avro_value)
+            .map_err(|err| AvroError::ParseAvroValue(err.to_string()))?;
         res.append(&mut avro_record);
         Ok(res)
     }
@@ -239,12 +239,12 @@ mod tests {
         async fn get_schema_by_id(&self, id: i32) -> AvroResult<ResolvedAvroSchema> {
             let json_schema = &get_test_avro_schema();
             let schema = Schema::parse_str(json_schema).expect("invalid test schema");
-            Ok(ResolvedAvroSchema::from(id, &schema))
+            Ok(ResolvedAvroSchema::from(id, &schema, vec![]))
         }
         async fn get_schema_by_name(&self, _: &str) -> AvroResult<ResolvedAvroSchema> {
             let json_schema = &get_test_avro_schema();
             let schema = Schema::parse_str(json_schema).expect("invalid test schema");
-            Ok(ResolvedAvroSchema::from(123, &schema))
+            Ok(ResolvedAvroSchema::from(123, &schema, vec![]))
         }
     }
 
@@ -338,6 +338,8 @@ mod tests {
         RecordField {
             name: name.into(),
             schema: schema,
+            default: None,
+            aliases: vec![],
         }
     }
 
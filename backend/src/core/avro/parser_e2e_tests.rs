@@ -18,31 +18,55 @@ mod tests {
         test_parsing_loop("2_schema.json", "2_good_input.json").await
     }
 
+    #[tokio::test]
+    async fn test_schema_references() {
+        let address = r#"{"type": "record", "name": "Address", "namespace": "com.example",
+            "fields": [{"name": "street", "type": "string"}]}"#;
+        let user = r#"{"type": "record", "name": "User", "namespace": "com.example",
+            "fields": [{"name": "name", "type": "string"}, {"name": "address", "type": "com.example.Address"}]}"#;
+        let sut = AvroParser::new(Arc::new(MockSchemaRegistry {
+            schema: user.into(),
+            references: vec![address.into()],
+        }));
+        let avro_json_in = r#"{"name": "Test", "address": {"street": "Main street"}}"#;
+        let avro = sut.json_to_avro(avro_json_in, "schema_name").await.unwrap();
+        let (_, avro_json_out) = sut.avro_to_json(&avro).await.unwrap();
+        assert_eq!(
+            JsonValue::from_str(&avro_json_out).unwrap(),
+            JsonValue::from_str(avro_json_in).unwrap()
+        );
+    }
+
     /// test fixture
     struct MockSchemaRegistry {
         schema: String,
+        references: Vec<String>,
+    }
+
+    impl MockSchemaRegistry {
+        fn resolve(&self, id: i32) -> ResolvedAvroSchema {
+            let (schema, references) = ApacheAvroSchema::parse_str_with_list(&self.schema, &self.references).unwrap();
+            ResolvedAvroSchema::from(id, &schema, references)
+        }
     }
 
     #[async_trait]
     impl SchemaProvider for MockSchemaRegistry {
         async fn get_schema_by_id(&self, id: i32) -> AvroResult<ResolvedAvroSchema> {
-            Ok(ResolvedAvroSchema::from(
-                id,
-                &ApacheAvroSchema::parse_str(&self.schema).unwrap(),
-            ))
+            Ok(self.resolve(id))
         }
         async fn get_schema_by_name(&self, _name: &str) -> AvroResult<ResolvedAvroSchema> {
-            Ok(ResolvedAvroSchema::from(
-                123,
-                &ApacheAvroSchema::parse_str(&self.schema).unwrap(),
-            ))
+            Ok(self.resolve(123))
         }
     }
 
     async fn test_parsing_loop(schema_file_name: &str, test_file_name: &str) {
         let avro_json_in = fs::read_to_string(format!("src/core/avro/test_files/{}", test_file_name)).unwrap();
         let schema = fs::read_to_string(format!("src/core/avro/test_files/{}", schema_file_name)).unwrap();
-        let sut = AvroParser::new(Arc::new(MockSchemaRegistry { schema }));
+        let sut = AvroParser::new(Arc::new(MockSchemaRegistry {
+            schema,
+            references: vec![],
+        }));
 
         // act/assert
         let json_to_avro_result = sut.json_to_avro(&avro_json_in, "schema_name").await;
@@ -55,6 +55,9 @@ impl ConfigurationProvider {
                 Some(s) => assert!(!s.endpoint.is_empty()),
                 None => {}
             }
+            if let Some(k) = &c.kafka_connect {
+                assert!(!k.endpoint.is_empty());
+            }
         });
         let as_store = StoreConfig::from(configuration);
         let raw_config = toml::to_string_pretty(&as_store)?;
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::{AuthenticationConfig, ClusterConfig, Favorites, InsulatorConfig, SchemaRegistryConfig, Theme};
+use super::{
+    AuthenticationConfig, ClusterConfig, Favorites, InsulatorConfig, KafkaConnectConfig, ProtobufConfig,
+    RecordStoreConfig, SchemaRegistryConfig, SubjectNameStrategyConfig, Theme,
+};
 
 #[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 pub struct StoreConfig {
@@ -26,6 +29,13 @@ pub struct StoreCluster {
     #[serde(rename = "schemaRegistry")]
     pub schema_registry: Option<SchemaRegistryConfig>,
     pub favorites: Option<Favorites>,
+    #[serde(rename = "recordStore")]
+    pub record_store: Option<RecordStoreConfig>,
+    pub protobuf: Option<ProtobufConfig>,
+    #[serde(rename = "subjectNameStrategy")]
+    pub subject_name_strategy: Option<SubjectNameStrategyConfig>,
+    #[serde(rename = "kafkaConnect")]
+    pub kafka_connect: Option<KafkaConnectConfig>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
@@ -83,6 +93,10 @@ fn store_cluster_to_config(id: String, store: StoreCluster) -> ClusterConfig {
         authentication: store.authentication.into(),
         schema_registry: store.schema_registry,
         favorites: store.favorites.unwrap_or_default(),
+        record_store: store.record_store,
+        protobuf: store.protobuf,
+        subject_name_strategy: store.subject_name_strategy,
+        kafka_connect: store.kafka_connect,
     }
 }
 
@@ -148,6 +162,10 @@ impl From<ClusterConfig> for StoreCluster {
             authentication: config.authentication.into(),
             schema_registry: config.schema_registry,
             favorites: Some(config.favorites),
+            record_store: config.record_store,
+            protobuf: config.protobuf,
+            subject_name_strategy: config.subject_name_strategy,
+            kafka_connect: config.kafka_connect,
         }
     }
 }
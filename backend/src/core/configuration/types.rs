@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
@@ -63,6 +63,17 @@ pub struct ClusterConfig {
     #[serde(rename = "schemaRegistry")]
     pub schema_registry: Option<SchemaRegistryConfig>,
     pub favorites: Favorites,
+    /// Persist the consumed records on disk instead of keeping them in memory
+    #[serde(rename = "recordStore")]
+    pub record_store: Option<RecordStoreConfig>,
+    /// Local protobuf schemas, used instead of the schema registry for the configured topics
+    pub protobuf: Option<ProtobufConfig>,
+    /// Strategy used to retrieve the schema registry subject of the produced records.
+    /// TopicNameStrategy is used if None
+    #[serde(rename = "subjectNameStrategy")]
+    pub subject_name_strategy: Option<SubjectNameStrategyConfig>,
+    #[serde(rename = "kafkaConnect")]
+    pub kafka_connect: Option<KafkaConnectConfig>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
@@ -90,6 +101,64 @@ pub struct SchemaRegistryConfig {
     pub password: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct KafkaConnectConfig {
+    /// Url of the Kafka Connect REST API
+    pub endpoint: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordStoreConfig {
+    /// Directory where the cluster database file is stored
+    pub location: String,
+    /// Maximum size of the database file. No limit if None
+    #[serde(rename = "maxSizeMb")]
+    pub max_size_mb: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProtobufConfig {
+    /// Paths to `.desc` descriptor sets or `.proto` files
+    pub files: Vec<String>,
+    /// Fully qualified name of the message to use for each topic
+    #[serde(rename = "topicMessageTypes")]
+    pub topic_message_types: HashMap<String, String>,
+}
+
+/// Subject naming strategies of the confluent serializers
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SubjectNameStrategy {
+    /// `{topic}-key` and `{topic}-value`
+    #[default]
+    #[serde(rename = "TopicNameStrategy")]
+    Topic,
+    /// Fully qualified name of the record
+    #[serde(rename = "RecordNameStrategy")]
+    Record,
+    /// `{topic}-{fully qualified name of the record}`
+    #[serde(rename = "TopicRecordNameStrategy")]
+    TopicRecord,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubjectNameStrategyConfig {
+    /// Strategy used for the topics that don't match any pattern
+    #[serde(default)]
+    pub default: SubjectNameStrategy,
+    /// Strategy by topic. The first pattern matching the whole topic name is used
+    #[serde(rename = "topicPatterns", default)]
+    pub topic_patterns: Vec<TopicSubjectNameStrategy>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TopicSubjectNameStrategy {
+    /// Regex of the topic names
+    pub pattern: String,
+    pub strategy: SubjectNameStrategy,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct Favorites {
     pub topics: Vec<String>,
@@ -1,6 +1,6 @@
 use crate::core::{
     configuration::{build_kafka_client_config, ClusterConfig},
-    consumer::types::{ConsumerConfiguration, ConsumerOffsetConfiguration, ConsumerState},
+    consumer::types::{ConsumerConfiguration, ConsumerOffsetConfiguration, ConsumerState, TopicSubscription},
     error_callback::ErrorCallback,
     record_store::TopicStore,
     types::RawKafkaRecord,
@@ -9,18 +9,31 @@ use futures::{lock::Mutex, StreamExt};
 use log::{debug, error, warn};
 use rdkafka::{
     consumer::{Consumer as ApacheKafkaConsumer, StreamConsumer},
-    message::OwnedMessage,
+    message::{Headers, OwnedMessage},
     Message, Offset, TopicPartitionList,
 };
-use std::{sync::Arc, time::Duration};
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tauri::async_runtime::JoinHandle;
 
 use super::error::{ConsumerError, ConsumerResult};
 
+/// Max time to wait for a record before checking if the snapshot is completed
+const SNAPSHOT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct KafkaConsumer {
     cluster_config: ClusterConfig,
-    topic: String,
+    name: String,
+    subscription: TopicSubscription,
     loop_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    is_completed: Arc<AtomicBool>,
     error_callback: ErrorCallback<ConsumerError>,
     pub topic_store: Arc<TopicStore>,
     timeout: Duration,
@@ -29,7 +42,7 @@ pub struct KafkaConsumer {
 impl KafkaConsumer {
     pub fn new(
         cluster_config: &ClusterConfig,
-        topic: &str,
+        name: &str,
         topic_store: TopicStore,
         error_cb: ErrorCallback<ConsumerError>,
         timeout: Duration,
@@ -37,8 +50,10 @@ impl KafkaConsumer {
         KafkaConsumer {
             error_callback: error_cb,
             cluster_config: cluster_config.clone(),
-            topic: topic.to_string(),
+            name: name.to_string(),
+            subscription: TopicSubscription::from(name),
             loop_handle: Arc::new(Mutex::new(None)),
+            is_completed: Arc::new(AtomicBool::new(false)),
             topic_store: Arc::new(topic_store),
             timeout,
         }
@@ -48,15 +63,36 @@ impl KafkaConsumer {
         consumer: &rdkafka::consumer::StreamConsumer,
         topics: &[&str],
         config: &ConsumerOffsetConfiguration,
+        partitions: Option<&[i32]>,
         tmo: Duration,
-    ) -> ConsumerResult<()> {
+    ) -> ConsumerResult<TopicPartitionList> {
         let metadata = consumer.fetch_metadata(if topics.len() == 1 { Some(topics[0]) } else { None }, tmo)?;
-        let topic_partition: Vec<_> = metadata
+        let all_topic_partition: Vec<_> = metadata
             .topics()
             .iter()
             .filter(|t| topics.contains(&t.name()))
             .flat_map(|t| t.partitions().iter().map(|p| (t.name(), p.id())))
             .collect();
+        if let Some(partitions) = partitions {
+            let unknown_partitions: Vec<_> = partitions
+                .iter()
+                .filter(|id| !all_topic_partition.iter().any(|(_, p)| p == *id))
+                .collect();
+            if !unknown_partitions.is_empty() {
+                return Err(ConsumerError::InvalidSubscription(format!(
+                    "Partitions {unknown_partitions:?} not found in the topics {topics:?}"
+                )));
+            }
+        }
+        let topic_partition: Vec<_> = all_topic_partition
+            .into_iter()
+            .filter(|(_, p)| partitions.map(|partitions| partitions.contains(p)).unwrap_or(true))
+            .collect();
+        if topic_partition.is_empty() {
+            return Err(ConsumerError::InvalidSubscription(format!(
+                "No partitions to assign for the topics {topics:?} and partitions {partitions:?}"
+            )));
+        }
 
         let end_offset_assignment = {
             let mut timestamp_assignment = consumer.assignment()?;
@@ -66,7 +102,7 @@ impl KafkaConsumer {
             consumer.offsets_for_times(timestamp_assignment, tmo)?
         };
 
-        match config {
+        let assignment = match config {
             ConsumerOffsetConfiguration::Beginning => {
                 let mut beginning_assignment = consumer.assignment()?;
                 for (t, p) in &topic_partition {
@@ -74,10 +110,12 @@ impl KafkaConsumer {
                 }
                 debug!("Partition to assign {:?}", end_offset_assignment);
                 consumer.assign(&beginning_assignment)?;
+                beginning_assignment
             }
             ConsumerOffsetConfiguration::End => {
                 debug!("Partition to assign {:?}", end_offset_assignment);
                 consumer.assign(&end_offset_assignment)?;
+                end_offset_assignment
             }
             ConsumerOffsetConfiguration::Custom { start_timestamp, .. } => {
                 let mut custom_assignment = consumer.assignment()?;
@@ -102,18 +140,33 @@ impl KafkaConsumer {
                 }
                 debug!("Partition to assign {:?}", custom_assignment);
                 consumer.assign(&custom_assignment)?;
+                custom_assignment
             }
-        }
+            ConsumerOffsetConfiguration::Offsets { start_offsets, .. } => {
+                let mut offsets_assignment = consumer.assignment()?;
+                for (t, p) in &topic_partition {
+                    let offset = start_offsets
+                        .iter()
+                        .find(|o| o.topic == *t && o.partition_id == *p)
+                        .map(|o| Offset::Offset(o.offset))
+                        .unwrap_or(Offset::Beginning);
+                    offsets_assignment.add_partition_offset(t, *p, offset)?;
+                }
+                debug!("Partition to assign {:?}", offsets_assignment);
+                consumer.assign(&offsets_assignment)?;
+                offsets_assignment
+            }
+        };
         debug!("Partition assigned");
-        Ok(())
+        Ok(assignment)
     }
 
     pub async fn start(&self, consumer_config: &ConsumerConfiguration) -> ConsumerResult<()> {
-        let topic = self.topic.clone();
+        let name = &self.name;
         if self.loop_handle.lock().await.is_some() {
             warn!("Try to start an already running consumer");
             return Err(ConsumerError::AlreadyRunning(format!(
-                "A consumer is already running for {topic}"
+                "A consumer is already running for {name}"
             )));
         }
         // set the handle to the consumer loop
@@ -123,23 +176,44 @@ impl KafkaConsumer {
                 .create()
                 .expect("Unable to create kafka the consumer");
 
+            // retrieve the topics to consume
+            let topics = resolve_topics(&consumer, &self.subscription, self.timeout)?;
+            let topics: Vec<_> = topics.iter().map(|t| t.as_str()).collect();
+
             // configure the consumer
-            if let Err(err) = KafkaConsumer::update_consumer_assignment(
+            let assignment = KafkaConsumer::update_consumer_assignment(
                 &consumer,
-                &[&topic],
+                &topics,
                 &consumer_config.consumer_start_config,
+                consumer_config.partitions.as_deref(),
                 self.timeout,
-            ) {
-                error!("{:?}", err);
-                (self.error_callback)(err);
-                panic!("Unable to continue");
-            }
+            )?;
+
+            // capture the end offsets to stop the consumer in snapshot mode
+            let snapshot = if consumer_config.snapshot {
+                Some(Snapshot::from_assignment(&consumer, &assignment, self.timeout)?)
+            } else {
+                None
+            };
+            self.is_completed.store(false, Ordering::Relaxed);
 
             let loop_handle = self.loop_handle.clone();
+            let is_completed = self.is_completed.clone();
             let topic_store = self.topic_store.clone();
             let consumer_config = consumer_config.clone();
             let error_callback = self.error_callback.clone();
-            async move { consumer_loop(&consumer, &consumer_config, &topic_store, &error_callback, loop_handle).await }
+            async move {
+                consumer_loop(
+                    &consumer,
+                    &consumer_config,
+                    &topic_store,
+                    &error_callback,
+                    snapshot,
+                    is_completed,
+                    loop_handle,
+                )
+                .await
+            }
         }));
         Ok(())
     }
@@ -151,6 +225,7 @@ impl KafkaConsumer {
     pub async fn get_consumer_state(&self) -> ConsumerResult<ConsumerState> {
         Ok(ConsumerState {
             is_running: self.loop_handle.clone().lock().await.is_some(),
+            is_completed: self.is_completed.load(Ordering::Relaxed),
             record_count: self
                 .topic_store
                 .get_records_count()
@@ -159,16 +234,148 @@ impl KafkaConsumer {
     }
 }
 
-fn get_stop_timestamp(consumer_config: &ConsumerConfiguration) -> Option<u64> {
-    // retrieve the stop timestamp if specified
-    if let ConsumerOffsetConfiguration::Custom {
-        stop_timestamp: Some(stop),
-        ..
-    } = &consumer_config.consumer_start_config
-    {
-        Some(*stop as u64)
-    } else {
-        None
+fn resolve_topics(
+    consumer: &StreamConsumer,
+    subscription: &TopicSubscription,
+    tmo: Duration,
+) -> ConsumerResult<Vec<String>> {
+    let topics = match subscription {
+        TopicSubscription::Topics(topics) => topics.clone(),
+        TopicSubscription::Pattern(pattern) => {
+            let regex = Regex::new(pattern)
+                .map_err(|err| ConsumerError::InvalidSubscription(format!("Invalid topic pattern {pattern}: {err}")))?;
+            let metadata = consumer.fetch_metadata(None, tmo)?;
+            metadata
+                .topics()
+                .iter()
+                .map(|t| t.name())
+                .filter(|t| regex.is_match(t))
+                .map(|t| t.to_string())
+                .collect()
+        }
+    };
+    if topics.is_empty() {
+        return Err(ConsumerError::InvalidSubscription(format!(
+            "No topics to consume for {subscription:?}"
+        )));
+    }
+    debug!("Topics to consume {:?}", topics);
+    Ok(topics)
+}
+
+/// Condition to stop consuming a partition
+#[derive(Debug, Default)]
+struct StopCondition {
+    timestamp: Option<u64>,
+    offsets: Option<HashMap<(String, i32), i64>>,
+}
+
+impl StopCondition {
+    fn from(consumer_config: &ConsumerConfiguration) -> Self {
+        // retrieve the stop timestamp or offsets if specified
+        match &consumer_config.consumer_start_config {
+            ConsumerOffsetConfiguration::Custom {
+                stop_timestamp: Some(stop),
+                ..
+            } => StopCondition {
+                timestamp: Some(*stop as u64),
+                ..Default::default()
+            },
+            ConsumerOffsetConfiguration::Offsets {
+                stop_offsets: Some(stop_offsets),
+                ..
+            } => StopCondition {
+                offsets: Some(
+                    stop_offsets
+                        .iter()
+                        .map(|o| ((o.topic.clone(), o.partition_id), o.offset))
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            _ => StopCondition::default(),
+        }
+    }
+
+    fn is_reached(&self, record: &RawKafkaRecord) -> bool {
+        let timestamp_reached = record.timestamp.unwrap_or(u64::MIN) >= self.timestamp.unwrap_or(u64::MAX);
+        let offset_reached = self
+            .offsets
+            .as_ref()
+            .and_then(|offsets| offsets.get(&(record.topic.clone(), record.partition)))
+            .map(|stop_offset| record.offset >= *stop_offset)
+            .unwrap_or(false);
+        timestamp_reached || offset_reached
+    }
+}
+
+/// End offsets (high watermarks) of the partitions still to be consumed in snapshot mode.
+/// The offsets are captured when the consumer starts.
+#[derive(Debug, Default)]
+struct Snapshot {
+    end_offsets: HashMap<(String, i32), i64>,
+}
+
+impl Snapshot {
+    fn from_assignment(
+        consumer: &StreamConsumer,
+        assignment: &TopicPartitionList,
+        tmo: Duration,
+    ) -> ConsumerResult<Self> {
+        let mut snapshot = Snapshot::default();
+        for e in assignment.elements() {
+            let (low, high) = consumer.fetch_watermarks(e.topic(), e.partition(), tmo)?;
+            let has_records_to_consume = match e.offset() {
+                Offset::End => false,
+                Offset::Offset(start) => start < high && low < high,
+                _ => low < high,
+            };
+            if has_records_to_consume {
+                snapshot
+                    .end_offsets
+                    .insert((e.topic().to_string(), e.partition()), high);
+            }
+        }
+        debug!("Snapshot end offsets {:?}", snapshot.end_offsets);
+        Ok(snapshot)
+    }
+
+    /// true if the record was produced after the snapshot started
+    fn is_beyond(&self, record: &RawKafkaRecord) -> bool {
+        self.end_offsets
+            .get(&(record.topic.clone(), record.partition))
+            .map(|end_offset| record.offset >= *end_offset)
+            .unwrap_or(true)
+    }
+
+    /// remove the record partition from the ones to consume
+    fn stop_partition(&mut self, record: &RawKafkaRecord) {
+        if self
+            .end_offsets
+            .remove(&(record.topic.clone(), record.partition))
+            .is_some()
+        {
+            debug!("Snapshot of {} partition {} stopped", record.topic, record.partition);
+        }
+    }
+
+    /// remove the partitions whose consumer position reached the end offset.
+    /// The position is used instead of the offset of the consumed records because the last offsets
+    /// of a partition may not be records, e.g. transaction markers or compacted records
+    fn update(&mut self, position: &TopicPartitionList) {
+        for e in position.elements() {
+            let key = (e.topic().to_string(), e.partition());
+            if let (Some(end_offset), Offset::Offset(offset)) = (self.end_offsets.get(&key), e.offset()) {
+                if offset >= *end_offset {
+                    debug!("Snapshot of {} partition {} completed", e.topic(), e.partition());
+                    self.end_offsets.remove(&key);
+                }
+            }
+        }
+    }
+
+    fn is_completed(&self) -> bool {
+        self.end_offsets.is_empty()
     }
 }
 
@@ -177,21 +384,51 @@ async fn consumer_loop(
     consumer_config: &ConsumerConfiguration,
     topic_store: &TopicStore,
     error_callback: &ErrorCallback<ConsumerError>,
+    mut snapshot: Option<Snapshot>,
+    is_completed: Arc<AtomicBool>,
     loop_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
 ) {
-    let stop_timestamp = get_stop_timestamp(consumer_config);
+    let stop_condition = StopCondition::from(consumer_config);
 
     // setup a new store
     topic_store
-        .setup(consumer_config.compactify)
+        .setup(consumer_config.compactify, consumer_config.parser_modes)
         .expect("Unable to create the internal store");
 
-    // infinite consumer loop
+    // infinite consumer loop, unless a snapshot is requested
     debug!("Start consumer loop");
     loop {
-        match consumer.stream().next().await {
+        let next = match snapshot.as_mut() {
+            Some(snapshot) => {
+                match consumer.position() {
+                    Ok(position) => snapshot.update(&position),
+                    Err(err) => warn!("Unable to retrieve the consumer position: {}", err),
+                }
+                if snapshot.is_completed() {
+                    debug!("All the assigned partitions reached the snapshot end offsets");
+                    is_completed.store(true, Ordering::Relaxed);
+                    *loop_handle.lock().await = None;
+                    break;
+                }
+                // no records are returned once the end of all the partitions is reached
+                match tokio::time::timeout(SNAPSHOT_CHECK_INTERVAL, consumer.stream().next()).await {
+                    Ok(next) => next,
+                    Err(_) => continue,
+                }
+            }
+            None => consumer.stream().next().await,
+        };
+        match next {
             Some(Ok(msg)) => {
-                handle_consumed_message(&msg.detach(), topic_store, stop_timestamp, consumer, error_callback).await;
+                let record = map_kafka_record(&msg.detach());
+                let stop = stop_condition.is_reached(&record)
+                    || snapshot.as_ref().map(|s| s.is_beyond(&record)).unwrap_or(false);
+                handle_consumed_message(&record, stop, topic_store, consumer, error_callback).await;
+                if stop {
+                    if let Some(snapshot) = snapshot.as_mut() {
+                        snapshot.stop_partition(&record);
+                    }
+                }
             }
             Some(Err(err)) => {
                 error!("An error occurs consuming from kafka: {}", err);
@@ -208,15 +445,14 @@ async fn consumer_loop(
 }
 
 async fn handle_consumed_message(
-    msg: &OwnedMessage,
+    record: &RawKafkaRecord,
+    stop: bool,
     topic_store: &TopicStore,
-    stop_timestamp: Option<u64>,
     consumer: &StreamConsumer,
     error_callback: &ErrorCallback<ConsumerError>,
 ) {
-    let record = map_kafka_record(msg);
-    if record.timestamp.unwrap_or(u64::MIN) < stop_timestamp.unwrap_or(u64::MAX) {
-        topic_store.insert_record(&record).await.unwrap_or_else(|err| {
+    if !stop {
+        topic_store.insert_record(record).await.unwrap_or_else(|err| {
             error_callback(ConsumerError::RecordStore(
                 "Unable to store the record".to_string(),
                 err,
@@ -257,5 +493,144 @@ fn map_kafka_record(msg: &OwnedMessage) -> RawKafkaRecord {
         partition: msg.partition(),
         offset: msg.offset(),
         timestamp: msg.timestamp().to_millis().map(|v| v as u64),
+        headers: msg
+            .headers()
+            .map(|headers| {
+                headers
+                    .iter()
+                    .map(|h| (h.key.to_string(), h.value.map(|v| v.to_owned())))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rdkafka::{Offset, TopicPartitionList};
+
+    use super::{Snapshot, StopCondition};
+    use crate::core::{
+        admin::TopicPartitionOffset,
+        consumer::{types::ConsumerOffsetConfiguration, ConsumerConfiguration},
+        types::RawKafkaRecord,
+    };
+
+    fn get_test_record(partition: i32, offset: i64, timestamp: u64) -> RawKafkaRecord {
+        RawKafkaRecord {
+            payload: None,
+            key: None,
+            topic: "topic".into(),
+            timestamp: Some(timestamp),
+            partition,
+            offset,
+            record_bytes: 0,
+            headers: vec![],
+        }
+    }
+
+    fn get_test_position(partition: i32, offset: i64) -> TopicPartitionList {
+        let mut position = TopicPartitionList::new();
+        position
+            .add_partition_offset("topic", partition, Offset::Offset(offset))
+            .unwrap();
+        position
+    }
+
+    fn topic_partition_offset(topic: &str, partition_id: i32, offset: i64) -> TopicPartitionOffset {
+        TopicPartitionOffset {
+            topic: topic.into(),
+            partition_id,
+            offset,
+        }
+    }
+
+    fn get_test_config(consumer_start_config: ConsumerOffsetConfiguration) -> ConsumerConfiguration {
+        ConsumerConfiguration {
+            compactify: false,
+            consumer_start_config,
+            partitions: None,
+            snapshot: false,
+            parser_modes: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_stop_condition() {
+        // never stop if no stop condition is specified
+        {
+            let sut = StopCondition::from(&get_test_config(ConsumerOffsetConfiguration::Beginning));
+            assert!(!sut.is_reached(&get_test_record(0, i64::MAX, u64::MAX - 1)));
+        }
+        // stop on timestamp
+        {
+            let sut = StopCondition::from(&get_test_config(ConsumerOffsetConfiguration::Custom {
+                start_timestamp: 0,
+                stop_timestamp: Some(100),
+            }));
+            assert!(!sut.is_reached(&get_test_record(0, 0, 99)));
+            assert!(sut.is_reached(&get_test_record(0, 0, 100)));
+        }
+        // stop on the offset of each partition
+        {
+            let sut = StopCondition::from(&get_test_config(ConsumerOffsetConfiguration::Offsets {
+                start_offsets: vec![topic_partition_offset("topic", 7, 120_000)],
+                stop_offsets: Some(vec![
+                    topic_partition_offset("topic", 7, 120_500),
+                    topic_partition_offset("other_topic", 1, 10),
+                ]),
+            }));
+            assert!(!sut.is_reached(&get_test_record(7, 120_499, 0)));
+            assert!(sut.is_reached(&get_test_record(7, 120_500, 0)));
+            // partitions without a stop offset are never stopped,
+            // the stop offsets of the same partition id of other topics are ignored
+            assert!(!sut.is_reached(&get_test_record(1, 120_500, 0)));
+        }
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut sut = Snapshot {
+            end_offsets: HashMap::from([(("topic".into(), 0), 10), (("topic".into(), 1), 5)]),
+        };
+        // records produced after the snapshot started are beyond the snapshot
+        assert!(!sut.is_beyond(&get_test_record(0, 9, 0)));
+        assert!(sut.is_beyond(&get_test_record(0, 10, 0)));
+        assert!(sut.is_beyond(&get_test_record(2, 0, 0)));
+        // the partition is completed when the consumer position reaches the end offset
+        sut.update(&get_test_position(0, 9));
+        assert!(!sut.is_completed());
+        sut.update(&get_test_position(0, 10));
+        assert!(!sut.is_completed());
+        // or when the partition is stopped
+        sut.stop_partition(&get_test_record(1, 2, 0));
+        assert!(sut.is_completed());
+    }
+
+    #[test]
+    fn test_snapshot_with_a_gap_at_the_end_of_the_partition() {
+        // the last offset is a transaction marker or a compacted record
+        let mut sut = Snapshot {
+            end_offsets: HashMap::from([(("topic".into(), 0), 10)]),
+        };
+        // the last record has offset 7, the consumer position moves past the gap
+        sut.update(&get_test_position(0, 8));
+        assert!(!sut.is_completed());
+        sut.update(&get_test_position(0, 10));
+        assert!(sut.is_completed());
+    }
+
+    #[test]
+    fn test_snapshot_ignores_logical_positions() {
+        let mut sut = Snapshot {
+            end_offsets: HashMap::from([(("topic".into(), 0), 10)]),
+        };
+        // nothing consumed yet
+        let mut position = TopicPartitionList::new();
+        position.add_partition_offset("topic", 0, Offset::Invalid).unwrap();
+        sut.update(&position);
+        assert!(!sut.is_completed());
     }
 }
@@ -5,6 +5,7 @@ pub enum ConsumerError {
     RDKafka(String),
     RecordStore(String, crate::core::record_store::StoreError),
     AlreadyRunning(String),
+    InvalidSubscription(String),
 }
 pub type ConsumerResult<T> = Result<T, ConsumerError>;
 
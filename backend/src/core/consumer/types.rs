@@ -1,9 +1,20 @@
 use serde::{Deserialize, Serialize};
 
+use crate::core::{admin::TopicPartitionOffset, types::ParserModes};
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ConsumerConfiguration {
     pub compactify: bool,
     pub consumer_start_config: ConsumerOffsetConfiguration,
+    /// Consume only the specified partition ids. All the partitions are consumed if None
+    pub partitions: Option<Vec<i32>>,
+    /// Stop the consumer once all the assigned partitions reach the end offsets
+    /// retrieved when the consumer started
+    #[serde(default)]
+    pub snapshot: bool,
+    /// Parser modes of key and value. The format is detected automatically if not set
+    #[serde(rename = "parserModes", default)]
+    pub parser_modes: ParserModes,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -14,6 +25,13 @@ pub enum ConsumerOffsetConfiguration {
         start_timestamp: i64,        //time in ms
         stop_timestamp: Option<i64>, //time in ms
     },
+    /// Explicit offset range for each topic partition.
+    /// Partitions without a start offset are consumed from the beginning.
+    Offsets {
+        start_offsets: Vec<TopicPartitionOffset>,
+        /// Stop offset (exclusive) for each topic partition
+        stop_offsets: Option<Vec<TopicPartitionOffset>>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -22,4 +40,58 @@ pub struct ConsumerState {
     pub is_running: bool,
     #[serde(rename = "recordCount")]
     pub record_count: usize,
+    /// true if the consumer stopped because all the records in the snapshot have been consumed
+    #[serde(rename = "isCompleted")]
+    pub is_completed: bool,
+}
+
+/// Topics read by a single consumer session.
+/// It is parsed from the consumer name, following the librdkafka subscription conventions:
+/// - `orders` consumes a single topic
+/// - `orders,payments` consumes a list of topics
+/// - `^orders-.*` consumes all the topics matching the regex
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopicSubscription {
+    Topics(Vec<String>),
+    Pattern(String),
+}
+
+impl From<&str> for TopicSubscription {
+    fn from(name: &str) -> Self {
+        if name.starts_with('^') {
+            TopicSubscription::Pattern(name.into())
+        } else {
+            TopicSubscription::Topics(
+                name.split(',')
+                    .map(|t| t.trim())
+                    .filter(|t| !t.is_empty())
+                    .map(|t| t.into())
+                    .collect(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TopicSubscription;
+
+    #[test]
+    fn test_parse_topic_subscription() {
+        // single topic
+        {
+            let res = TopicSubscription::from("orders");
+            assert_eq!(res, TopicSubscription::Topics(vec!["orders".into()]));
+        }
+        // list of topics
+        {
+            let res = TopicSubscription::from("orders, payments");
+            assert_eq!(res, TopicSubscription::Topics(vec!["orders".into(), "payments".into()]));
+        }
+        // regex
+        {
+            let res = TopicSubscription::from("^orders-.*");
+            assert_eq!(res, TopicSubscription::Pattern("^orders-.*".into()));
+        }
+    }
 }
@@ -0,0 +1,12 @@
+use crate::core::schema_registry::SchemaRegistryError;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum JsonSchemaError {
+    SchemaProvider(String, SchemaRegistryError),
+    SchemaCompilation(String),
+    InvalidJsonSchemaHeader(String),
+    ParseJsonValue(String),
+    Validation(String),
+}
+
+pub type JsonSchemaResult<T> = std::result::Result<T, JsonSchemaError>;
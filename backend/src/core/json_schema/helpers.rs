@@ -0,0 +1,43 @@
+use super::error::{JsonSchemaError, JsonSchemaResult};
+
+const MAGIC_BYTE: u8 = 0x00;
+
+/// Split a record serialized with the confluent json schema serializer into schema id and json payload
+pub(super) fn parse_record_header(raw: &[u8]) -> JsonSchemaResult<(i32, &[u8])> {
+    if raw.len() <= 5 || raw[0] != MAGIC_BYTE {
+        return Err(JsonSchemaError::InvalidJsonSchemaHeader(
+            "Supported json schema messages should start with 0x00 follow by the schema id (4 bytes)".into(),
+        ));
+    }
+    let id = i32::from_be_bytes([raw[1], raw[2], raw[3], raw[4]]);
+    Ok((id, &raw[5..]))
+}
+
+pub(super) fn build_record_header(schema_id: i32) -> Vec<u8> {
+    let mut res = vec![MAGIC_BYTE];
+    res.extend_from_slice(&schema_id.to_be_bytes());
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_record_header, parse_record_header};
+
+    #[test]
+    fn test_parse_record_header() {
+        let raw: Vec<u8> = vec![0x00, 0x00, 0x01, 0x86, 0xc5, b'{', b'}'];
+        let (id, payload) = parse_record_header(&raw).unwrap();
+        assert_eq!(id, 100037);
+        assert_eq!(payload, b"{}");
+    }
+
+    #[test]
+    fn test_plain_json_fails() {
+        assert!(parse_record_header(b"{\"a\": 1}").is_err());
+    }
+
+    #[test]
+    fn build_record_header_happy_path() {
+        assert_eq!(build_record_header(100037), vec![0x00, 0x00, 0x01, 0x86, 0xc5])
+    }
+}
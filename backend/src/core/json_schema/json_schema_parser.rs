@@ -0,0 +1,88 @@
+use std::{str::FromStr, sync::Arc};
+
+use serde_json::Value as JsonValue;
+
+use super::{
+    error::{JsonSchemaError, JsonSchemaResult},
+    helpers::{build_record_header, parse_record_header},
+    schema_provider::JsonSchemaProvider,
+};
+
+pub struct JsonSchemaParser<S: JsonSchemaProvider> {
+    schema_provider: Arc<S>,
+}
+
+impl<S: JsonSchemaProvider> JsonSchemaParser<S> {
+    pub fn new(schema_provider: Arc<S>) -> Self {
+        Self { schema_provider }
+    }
+
+    pub async fn json_schema_to_json(&self, raw: &[u8]) -> JsonSchemaResult<(i32, String)> {
+        let (id, payload) = parse_record_header(raw)?;
+        // make sure the schema id refers to a json schema
+        self.schema_provider.get_json_schema_by_id(id).await?;
+        let json: JsonValue =
+            serde_json::from_slice(payload).map_err(|err| JsonSchemaError::ParseJsonValue(err.to_string()))?;
+        Ok((id, json.to_string()))
+    }
+
+    /// Validate the json against the last version of the subject schema before serializing it
+    pub async fn json_to_json_schema(&self, json: &str, subject_name: &str) -> JsonSchemaResult<Vec<u8>> {
+        let schema = self.schema_provider.get_json_schema_by_name(subject_name).await?;
+        let json_value = JsonValue::from_str(json).map_err(|err| JsonSchemaError::ParseJsonValue(err.to_string()))?;
+        schema.validate(&json_value)?;
+        let mut res = build_record_header(schema.id);
+        res.append(&mut json_value.to_string().into_bytes());
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use crate::core::json_schema::{
+        error::{JsonSchemaError, JsonSchemaResult},
+        JsonSchemaParser, JsonSchemaProvider, ResolvedJsonSchema,
+    };
+
+    const TEST_SCHEMA: &str = r#"{
+        "type": "object",
+        "properties": { "name": { "type": "string" } },
+        "required": ["name"]
+    }"#;
+
+    struct MockSchemaRegistry;
+
+    #[async_trait]
+    impl JsonSchemaProvider for MockSchemaRegistry {
+        async fn get_json_schema_by_id(&self, id: i32) -> JsonSchemaResult<ResolvedJsonSchema> {
+            ResolvedJsonSchema::compile(id, TEST_SCHEMA)
+        }
+        async fn get_json_schema_by_name(&self, _name: &str) -> JsonSchemaResult<ResolvedJsonSchema> {
+            ResolvedJsonSchema::compile(123, TEST_SCHEMA)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parsing_loop() {
+        let sut = JsonSchemaParser::new(Arc::new(MockSchemaRegistry));
+        let raw = sut
+            .json_to_json_schema(r#"{ "name": "example" }"#, "topic-value")
+            .await
+            .unwrap();
+        assert_eq!(&raw[..5], &[0x00, 0x00, 0x00, 0x00, 0x7b]);
+        let (id, json) = sut.json_schema_to_json(&raw).await.unwrap();
+        assert_eq!(id, 123);
+        assert_eq!(json, r#"{"name":"example"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_produce_invalid_payload_fails() {
+        let sut = JsonSchemaParser::new(Arc::new(MockSchemaRegistry));
+        let res = sut.json_to_json_schema(r#"{ "name": 1 }"#, "topic-value").await;
+        assert!(matches!(res, Err(JsonSchemaError::Validation(_))));
+    }
+}
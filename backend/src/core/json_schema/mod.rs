@@ -0,0 +1,10 @@
+mod error;
+mod helpers;
+mod json_schema_parser;
+mod resolved_json_schema;
+mod schema_provider;
+
+pub use error::JsonSchemaError;
+pub use json_schema_parser::JsonSchemaParser;
+pub use resolved_json_schema::ResolvedJsonSchema;
+pub use schema_provider::JsonSchemaProvider;
@@ -0,0 +1,62 @@
+use std::{str::FromStr, sync::Arc};
+
+use jsonschema::JSONSchema;
+use serde_json::Value as JsonValue;
+
+use super::error::{JsonSchemaError, JsonSchemaResult};
+
+/// Compiled json schema retrieved from the schema registry
+#[derive(Clone, Debug)]
+pub struct ResolvedJsonSchema {
+    pub id: i32,
+    validator: Arc<JSONSchema>,
+}
+
+impl ResolvedJsonSchema {
+    pub fn compile(id: i32, source: &str) -> JsonSchemaResult<Self> {
+        let schema = JsonValue::from_str(source).map_err(|err| JsonSchemaError::ParseJsonValue(err.to_string()))?;
+        let validator = JSONSchema::compile(&schema)
+            .map_err(|err| JsonSchemaError::SchemaCompilation(format!("Unable to compile the schema {id}: {err}")))?;
+        Ok(Self {
+            id,
+            validator: Arc::new(validator),
+        })
+    }
+
+    pub fn validate(&self, value: &JsonValue) -> JsonSchemaResult<()> {
+        self.validator.validate(value).map_err(|errors| {
+            let errors: Vec<_> = errors.map(|err| format!("{}: {}", err.instance_path, err)).collect();
+            JsonSchemaError::Validation(errors.join("\n"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ResolvedJsonSchema;
+
+    const TEST_SCHEMA: &str = r#"{
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "age": { "type": "integer", "minimum": 0 }
+        },
+        "required": ["name"]
+    }"#;
+
+    #[test]
+    fn test_validate() {
+        let sut = ResolvedJsonSchema::compile(1, TEST_SCHEMA).unwrap();
+        assert!(sut.validate(&json!({"name": "example", "age": 10})).is_ok());
+        assert!(sut.validate(&json!({"age": 10})).is_err());
+        assert!(sut.validate(&json!({"name": "example", "age": -1})).is_err());
+    }
+
+    #[test]
+    fn test_invalid_schema_fails() {
+        assert!(ResolvedJsonSchema::compile(1, "{").is_err());
+        assert!(ResolvedJsonSchema::compile(1, r#"{"type": 123}"#).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::core::schema_registry::CachedSchemaRegistry;
+
+use super::{
+    error::{JsonSchemaError, JsonSchemaResult},
+    ResolvedJsonSchema,
+};
+
+#[async_trait]
+pub trait JsonSchemaProvider: Send + Sync {
+    async fn get_json_schema_by_id(&self, id: i32) -> JsonSchemaResult<ResolvedJsonSchema>;
+    async fn get_json_schema_by_name(&self, name: &str) -> JsonSchemaResult<ResolvedJsonSchema>;
+}
+
+#[async_trait]
+impl JsonSchemaProvider for CachedSchemaRegistry {
+    async fn get_json_schema_by_id(&self, id: i32) -> JsonSchemaResult<ResolvedJsonSchema> {
+        self.get_json_schema_by_id(id)
+            .await
+            .map_err(|err| JsonSchemaError::SchemaProvider(format!("Unable to retrieve the schema id {id}"), err))
+    }
+    async fn get_json_schema_by_name(&self, name: &str) -> JsonSchemaResult<ResolvedJsonSchema> {
+        self.get_last_json_schema(name)
+            .await
+            .map_err(|err| JsonSchemaError::SchemaProvider(format!("Unable to retrieve the schema {name}"), err))
+    }
+}
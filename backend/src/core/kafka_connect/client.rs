@@ -0,0 +1,288 @@
+use log::debug;
+use serde::Deserialize;
+use std::collections::HashMap;
+use url::Url;
+
+use crate::core::schema_registry::{BasicAuth, HttpClient, HttpClientError, ReqwestClient};
+
+use super::error::{KafkaConnectError, KafkaConnectResult};
+use super::types::ConnectorStatus;
+
+#[derive(Deserialize)]
+struct ExpandedConnector {
+    status: ConnectorStatus,
+}
+
+#[derive(Deserialize)]
+struct ConnectorInfo {
+    config: HashMap<String, String>,
+}
+
+pub struct KafkaConnectClient<C: HttpClient = ReqwestClient> {
+    http_client: C,
+    endpoint: String,
+}
+
+impl KafkaConnectClient<ReqwestClient> {
+    pub fn new(endpoint: &str, username: Option<&str>, password: Option<&str>) -> Self {
+        assert!(!endpoint.is_empty());
+        let auth = username.map(|username| BasicAuth {
+            username: username.to_string(),
+            password: password.map(|p| p.to_owned()),
+        });
+        KafkaConnectClient::new_with_client(endpoint, ReqwestClient::new(auth))
+    }
+}
+
+impl<C: HttpClient> KafkaConnectClient<C> {
+    pub fn new_with_client(endpoint: &str, http_client: C) -> Self {
+        Self {
+            http_client,
+            endpoint: endpoint.into(),
+        }
+    }
+
+    /// List all the connectors with their status, sorted by name
+    pub async fn list_connectors(&self) -> KafkaConnectResult<Vec<ConnectorStatus>> {
+        let mut url = self.build_url(&["connectors"])?;
+        url.query_pairs_mut().append_pair("expand", "status");
+        let res: HashMap<String, ExpandedConnector> = self.http_client.get(url.as_str()).await?;
+        let mut connectors: Vec<_> = res.into_values().map(|c| c.status).collect();
+        connectors.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(connectors)
+    }
+
+    pub async fn get_connector_status(&self, name: &str) -> KafkaConnectResult<ConnectorStatus> {
+        let url = self.build_url(&["connectors", name, "status"])?;
+        self.http_client
+            .get(url.as_str())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    pub async fn get_connector_config(&self, name: &str) -> KafkaConnectResult<HashMap<String, String>> {
+        let url = self.build_url(&["connectors", name, "config"])?;
+        self.http_client
+            .get(url.as_str())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    /// Replace the configuration of the connector, creating it if not exists.
+    /// Returns the configuration applied by the Connect cluster
+    pub async fn update_connector_config(
+        &self,
+        name: &str,
+        config: &HashMap<String, String>,
+    ) -> KafkaConnectResult<HashMap<String, String>> {
+        debug!("Update connector {} configuration", name);
+        let url = self.build_url(&["connectors", name, "config"])?;
+        let res: ConnectorInfo = self.http_client.put(url.as_str(), config).await?;
+        Ok(res.config)
+    }
+
+    pub async fn pause_connector(&self, name: &str) -> KafkaConnectResult<()> {
+        debug!("Pause connector {}", name);
+        let url = self.build_url(&["connectors", name, "pause"])?;
+        self.http_client
+            .put_without_body(url.as_str())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    pub async fn resume_connector(&self, name: &str) -> KafkaConnectResult<()> {
+        debug!("Resume connector {}", name);
+        let url = self.build_url(&["connectors", name, "resume"])?;
+        self.http_client
+            .put_without_body(url.as_str())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    /// Restart the connector. The tasks are restarted as well if `include_tasks` is set,
+    /// only the failed ones if `only_failed` is set
+    pub async fn restart_connector(&self, name: &str, include_tasks: bool, only_failed: bool) -> KafkaConnectResult<()> {
+        debug!("Restart connector {}", name);
+        let mut url = self.build_url(&["connectors", name, "restart"])?;
+        url.query_pairs_mut()
+            .append_pair("includeTasks", &include_tasks.to_string())
+            .append_pair("onlyFailed", &only_failed.to_string());
+        self.http_client
+            .post(url.as_str(), ())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    pub async fn restart_task(&self, name: &str, task_id: i32) -> KafkaConnectResult<()> {
+        debug!("Restart task {} of connector {}", task_id, name);
+        let url = self.build_url(&["connectors", name, "tasks", &task_id.to_string(), "restart"])?;
+        self.http_client
+            .post(url.as_str(), ())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    pub async fn delete_connector(&self, name: &str) -> KafkaConnectResult<()> {
+        debug!("Delete connector {}", name);
+        let url = self.build_url(&["connectors", name])?;
+        self.http_client
+            .delete(url.as_str())
+            .await
+            .map_err(|err| Self::map_connector_error(name, err))
+    }
+
+    /// Append the path segments to the endpoint, encoding them
+    fn build_url(&self, segments: &[&str]) -> KafkaConnectResult<Url> {
+        let mut url = Url::parse(&self.endpoint)?;
+        url.path_segments_mut()
+            .map_err(|_| KafkaConnectError::InvalidUrl(format!("Invalid Kafka Connect endpoint {}", self.endpoint)))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn map_connector_error(name: &str, err: HttpClientError) -> KafkaConnectError {
+        match err {
+            HttpClientError::Code(404) => KafkaConnectError::ConnectorNotFound(name.into()),
+            _ => err.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use httpmock::{
+        Method::{DELETE, GET, POST, PUT},
+        MockServer,
+    };
+
+    use crate::core::kafka_connect::KafkaConnectError;
+
+    use super::KafkaConnectClient;
+
+    #[tokio::test]
+    async fn test_list_connectors() {
+        let server = MockServer::start();
+        let server_mock = server.mock(|when, then| {
+            when.method(GET).path("/connectors").query_param("expand", "status");
+            then.status(200).header("content-type", "application/json").body(
+                r#"{
+                    "sink-b": {"status": {"name": "sink-b", "type": "sink",
+                        "connector": {"state": "RUNNING", "worker_id": "10.0.0.1:8083"},
+                        "tasks": [{"id": 0, "state": "FAILED", "worker_id": "10.0.0.1:8083", "trace": "boom"}]}},
+                    "source-a": {"status": {"name": "source-a", "type": "source",
+                        "connector": {"state": "PAUSED", "worker_id": "10.0.0.2:8083"},
+                        "tasks": []}}
+                }"#,
+            );
+        });
+        let sut = KafkaConnectClient::new(&server.base_url(), None, None);
+        let res = sut.list_connectors().await.expect("Unable to list the connectors");
+        server_mock.assert();
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].name, "sink-b");
+        assert_eq!(res[0].connector_type.as_deref(), Some("sink"));
+        assert_eq!(res[0].connector.worker_id, "10.0.0.1:8083");
+        assert_eq!(res[0].tasks[0].state, "FAILED");
+        assert_eq!(res[0].tasks[0].trace.as_deref(), Some("boom"));
+        assert_eq!(res[1].name, "source-a");
+        assert_eq!(res[1].connector.state, "PAUSED");
+    }
+
+    #[tokio::test]
+    async fn test_connector_config() {
+        let server = MockServer::start();
+        let get_mock = server.mock(|when, then| {
+            when.method(GET).path("/connectors/my%20connector/config");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"connector.class": "FileStreamSink", "tasks.max": "1"}"#);
+        });
+        let put_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/connectors/my%20connector/config")
+                .json_body(serde_json::json!({"tasks.max": "2"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"name": "my connector", "config": {"tasks.max": "2"}, "tasks": [], "type": "sink"}"#);
+        });
+        let sut = KafkaConnectClient::new(&server.base_url(), None, None);
+        let config = sut.get_connector_config("my connector").await.unwrap();
+        assert_eq!(config.get("tasks.max").map(String::as_str), Some("1"));
+        let new_config = HashMap::from([("tasks.max".to_string(), "2".to_string())]);
+        let res = sut.update_connector_config("my connector", &new_config).await.unwrap();
+        assert_eq!(res, new_config);
+        get_mock.assert();
+        put_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_connector_actions() {
+        let server = MockServer::start();
+        let pause_mock = server.mock(|when, then| {
+            when.method(PUT).path("/connectors/connector/pause");
+            then.status(202);
+        });
+        let resume_mock = server.mock(|when, then| {
+            when.method(PUT).path("/connectors/connector/resume");
+            then.status(202);
+        });
+        let restart_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/connectors/connector/restart")
+                .query_param("includeTasks", "true")
+                .query_param("onlyFailed", "false");
+            then.status(202);
+        });
+        let restart_task_mock = server.mock(|when, then| {
+            when.method(POST).path("/connectors/connector/tasks/1/restart");
+            then.status(204);
+        });
+        let delete_mock = server.mock(|when, then| {
+            when.method(DELETE).path("/connectors/connector");
+            then.status(204);
+        });
+        let sut = KafkaConnectClient::new(&server.base_url(), None, None);
+        assert!(sut.pause_connector("connector").await.is_ok());
+        assert!(sut.resume_connector("connector").await.is_ok());
+        assert!(sut.restart_connector("connector", true, false).await.is_ok());
+        assert!(sut.restart_task("connector", 1).await.is_ok());
+        assert!(sut.delete_connector("connector").await.is_ok());
+        pause_mock.assert();
+        resume_mock.assert();
+        restart_mock.assert();
+        restart_task_mock.assert();
+        delete_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/connectors/missing/status");
+            then.status(404);
+        });
+        server.mock(|when, then| {
+            when.method(PUT).path("/connectors/connector/pause");
+            then.status(409);
+        });
+        let sut = KafkaConnectClient::new(&server.base_url(), None, None);
+        assert_eq!(
+            sut.get_connector_status("missing").await,
+            Err(KafkaConnectError::ConnectorNotFound("missing".into()))
+        );
+        assert_eq!(
+            sut.pause_connector("connector").await,
+            Err(KafkaConnectError::RebalanceInProgress)
+        );
+    }
+
+    #[test]
+    fn test_build_url_keeps_the_endpoint_path() {
+        let sut = KafkaConnectClient::new("http://localhost:8083/connect/", None, None);
+        let url = sut.build_url(&["connectors", "a/b", "status"]).unwrap();
+        assert_eq!(url.as_str(), "http://localhost:8083/connect/connectors/a%2Fb/status");
+    }
+}
@@ -0,0 +1,31 @@
+use crate::core::schema_registry::HttpClientError;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum KafkaConnectError {
+    ConnectorNotFound(String),
+    /// The Connect cluster is rebalancing, the request can be retried later
+    RebalanceInProgress,
+    InvalidConfiguration(String),
+    HttpClient(String),
+    InvalidUrl(String),
+}
+
+pub type KafkaConnectResult<T> = core::result::Result<T, KafkaConnectError>;
+
+impl From<url::ParseError> for KafkaConnectError {
+    fn from(url: url::ParseError) -> Self {
+        Self::InvalidUrl(url.to_string())
+    }
+}
+
+impl From<HttpClientError> for KafkaConnectError {
+    fn from(err: HttpClientError) -> Self {
+        match err {
+            HttpClientError::Code(409) => KafkaConnectError::RebalanceInProgress,
+            HttpClientError::Code(400) | HttpClientError::Code(422) => {
+                KafkaConnectError::InvalidConfiguration("The connector configuration is not valid".into())
+            }
+            _ => KafkaConnectError::HttpClient(format!("Http client error {err:?}")),
+        }
+    }
+}
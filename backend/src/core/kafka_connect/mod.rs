@@ -0,0 +1,7 @@
+mod client;
+mod error;
+mod types;
+
+pub use client::KafkaConnectClient;
+pub use error::*;
+pub use types::*;
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Status of a connector and its tasks as reported by the Connect workers
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConnectorStatus {
+    pub name: String,
+    /// Either "source" or "sink". None if the worker doesn't report it
+    #[serde(rename = "type")]
+    pub connector_type: Option<String>,
+    pub connector: ConnectorState,
+    pub tasks: Vec<TaskState>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConnectorState {
+    /// One of UNASSIGNED, RUNNING, PAUSED, FAILED or RESTARTING
+    pub state: String,
+    #[serde(rename = "workerId", alias = "worker_id")]
+    pub worker_id: String,
+    /// Stack trace of the failure, if any
+    pub trace: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TaskState {
+    pub id: i32,
+    pub state: String,
+    #[serde(rename = "workerId", alias = "worker_id")]
+    pub worker_id: String,
+    pub trace: Option<String>,
+}
@@ -2,7 +2,10 @@ pub mod admin;
 pub mod avro;
 pub mod configuration;
 pub mod consumer;
+pub mod json_schema;
+pub mod kafka_connect;
 pub mod parser;
+pub mod protobuf;
 
 pub mod error_callback;
 pub mod producer;
@@ -1,8 +1,14 @@
-use crate::core::avro::AvroError;
+use crate::core::{avro::AvroError, json_schema::JsonSchemaError, protobuf::ProtobufError};
 
+#[derive(Debug)]
 pub enum ParserError {
     MissingAvroConfiguration,
+    MissingProtobufConfiguration,
+    MissingJsonSchemaConfiguration,
     Avro(AvroError),
+    Protobuf(ProtobufError),
+    JsonSchema(JsonSchemaError),
+    SubjectName(String),
 }
 
 pub type ParserResult<T> = Result<T, ParserError>;
@@ -12,3 +18,15 @@ impl From<AvroError> for ParserError {
         ParserError::Avro(value)
     }
 }
+
+impl From<ProtobufError> for ParserError {
+    fn from(value: ProtobufError) -> Self {
+        ParserError::Protobuf(value)
+    }
+}
+
+impl From<JsonSchemaError> for ParserError {
+    fn from(value: JsonSchemaError) -> Self {
+        ParserError::JsonSchema(value)
+    }
+}
@@ -1,36 +1,56 @@
 mod error;
 mod string_parser;
+mod subject_name;
 
 use std::sync::Arc;
 
 use crate::core::{
     avro::{AvroParser, SchemaProvider},
+    json_schema::{JsonSchemaParser, JsonSchemaProvider},
+    protobuf::{LocalProtoSchemas, ProtoSchemaProvider, ProtobufParser},
     schema_registry::CachedSchemaRegistry,
-    types::{ParsedKafkaRecord, RawKafkaRecord},
+    types::{ParsedKafkaRecord, ParserModes, RawKafkaRecord, RecordPart},
 };
 
-use string_parser::parse_string;
+use string_parser::{parse_headers, parse_string};
 
 pub use self::error::ParserError;
 use self::error::ParserResult;
+pub use self::subject_name::SubjectNameResolver;
 
 use super::types::ParserMode;
 
-pub struct Parser<C: SchemaProvider = CachedSchemaRegistry> {
+pub struct Parser<C: SchemaProvider + ProtoSchemaProvider + JsonSchemaProvider = CachedSchemaRegistry> {
     avro_parser: Option<AvroParser<C>>,
+    protobuf_parser: Option<ProtobufParser<C>>,
+    json_schema_parser: Option<JsonSchemaParser<C>>,
+    subject_name_resolver: SubjectNameResolver,
 }
 
-impl<C: SchemaProvider> Parser<C> {
-    pub fn new(schema_registry_client: Option<Arc<C>>) -> Self {
+impl<C: SchemaProvider + ProtoSchemaProvider + JsonSchemaProvider> Parser<C> {
+    pub fn new(
+        schema_registry_client: Option<Arc<C>>,
+        local_proto_schemas: Option<LocalProtoSchemas>,
+        subject_name_resolver: SubjectNameResolver,
+    ) -> Self {
+        let protobuf_parser = match (&schema_registry_client, local_proto_schemas) {
+            (None, None) => None,
+            (client, local_schemas) => Some(ProtobufParser::new(client.clone(), local_schemas)),
+        };
         Parser {
-            avro_parser: schema_registry_client.map(|client| AvroParser::new(client)),
+            avro_parser: schema_registry_client.clone().map(|client| AvroParser::new(client)),
+            json_schema_parser: schema_registry_client.map(|client| JsonSchemaParser::new(client)),
+            protobuf_parser,
+            subject_name_resolver,
         }
     }
 
+    /// Parse key and value of the record independently.
+    /// If the mode is not specified, the format is detected trying all the supported modes before falling back to string
     pub async fn parse_from_kafka_record(
         &self,
         record: &RawKafkaRecord,
-        mode: ParserMode,
+        modes: &ParserModes,
     ) -> ParserResult<ParsedKafkaRecord> {
         let RawKafkaRecord {
             payload,
@@ -40,21 +60,18 @@ impl<C: SchemaProvider> Parser<C> {
             partition,
             offset,
             record_bytes,
+            headers,
         } = record.clone();
-        let (key, payload, schema_id) = match mode {
-            ParserMode::String => (key.map(|v| parse_string(&v)), payload.map(|v| parse_string(&v)), None),
-            ParserMode::Avro => {
-                let avro_parser = self.avro_parser.as_ref().ok_or(ParserError::MissingAvroConfiguration)?;
-                let res = match payload {
-                    Some(v) => Some(avro_parser.avro_to_json(&v).await?),
-                    None => None,
-                };
-                (
-                    key.map(|v| parse_string(&v)),
-                    res.clone().map(|v| v.1),
-                    res.map(|v| v.0),
-                )
+        let (key, key_schema_id) = match key {
+            Some(v) => self.parse_record_part(&v, &topic, RecordPart::Key, modes.key).await?,
+            None => (None, None),
+        };
+        let (payload, schema_id) = match payload {
+            Some(v) => {
+                self.parse_record_part(&v, &topic, RecordPart::Value, modes.value)
+                    .await?
             }
+            None => (None, None),
         };
         Ok(ParsedKafkaRecord {
             key,
@@ -64,21 +81,252 @@ impl<C: SchemaProvider> Parser<C> {
             partition,
             offset,
             schema_id,
+            key_schema_id,
             record_bytes,
+            headers: parse_headers(&headers),
         })
     }
 
-    pub async fn parse_payload_to_avro(&self, payload: &str, topic_name: &str) -> ParserResult<Vec<u8>> {
+    async fn parse_record_part(
+        &self,
+        raw: &[u8],
+        topic: &str,
+        part: RecordPart,
+        mode: Option<ParserMode>,
+    ) -> ParserResult<(Option<String>, Option<i32>)> {
+        match mode {
+            Some(mode) => self.parse_with_mode(raw, topic, part, mode).await,
+            None => {
+                for mode in [ParserMode::Avro, ParserMode::Protobuf, ParserMode::Json] {
+                    if let Ok(res) = self.parse_with_mode(raw, topic, part, mode).await {
+                        return Ok(res);
+                    }
+                }
+                self.parse_with_mode(raw, topic, part, ParserMode::String).await
+            }
+        }
+    }
+
+    async fn parse_with_mode(
+        &self,
+        raw: &[u8],
+        topic: &str,
+        part: RecordPart,
+        mode: ParserMode,
+    ) -> ParserResult<(Option<String>, Option<i32>)> {
+        match mode {
+            ParserMode::String => Ok((Some(parse_string(raw)), None)),
+            ParserMode::Avro => {
+                let avro_parser = self.avro_parser.as_ref().ok_or(ParserError::MissingAvroConfiguration)?;
+                let (schema_id, json) = avro_parser.avro_to_json(raw).await?;
+                Ok((Some(json), Some(schema_id)))
+            }
+            ParserMode::Protobuf => {
+                let protobuf_parser = self
+                    .protobuf_parser
+                    .as_ref()
+                    .ok_or(ParserError::MissingProtobufConfiguration)?;
+                let (schema_id, json) = protobuf_parser.protobuf_to_json(raw, topic, part).await?;
+                Ok((Some(json), schema_id))
+            }
+            ParserMode::Json => {
+                let json_schema_parser = self
+                    .json_schema_parser
+                    .as_ref()
+                    .ok_or(ParserError::MissingJsonSchemaConfiguration)?;
+                let (schema_id, json) = json_schema_parser.json_schema_to_json(raw).await?;
+                Ok((Some(json), Some(schema_id)))
+            }
+        }
+    }
+
+    /// The record name is the fully qualified name of the record type, used by the record name strategies
+    pub async fn parse_payload_to_avro(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ParserResult<Vec<u8>> {
         if let Some(avro_parser) = self.avro_parser.as_ref() {
-            Ok(avro_parser
-                .json_to_avro(payload, &format!("{topic_name}-value"))
-                .await?)
+            let subject_name = self.subject_name_resolver.subject_name(topic_name, part, record_name)?;
+            Ok(avro_parser.json_to_avro(payload, &subject_name).await?)
         } else {
             Err(ParserError::MissingAvroConfiguration)
         }
     }
 
+    pub async fn parse_payload_to_protobuf(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ParserResult<Vec<u8>> {
+        if let Some(protobuf_parser) = self.protobuf_parser.as_ref() {
+            // local schemas take precedence over the schema registry
+            if let Some(res) = protobuf_parser.json_to_local_protobuf(payload, topic_name, part)? {
+                return Ok(res);
+            }
+            let subject_name = self.subject_name_resolver.subject_name(topic_name, part, record_name)?;
+            Ok(protobuf_parser
+                .json_to_protobuf(payload, &subject_name, record_name)
+                .await?)
+        } else {
+            Err(ParserError::MissingProtobufConfiguration)
+        }
+    }
+
+    pub async fn parse_payload_to_json_schema(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ParserResult<Vec<u8>> {
+        if let Some(json_schema_parser) = self.json_schema_parser.as_ref() {
+            let subject_name = self.subject_name_resolver.subject_name(topic_name, part, record_name)?;
+            Ok(json_schema_parser.json_to_json_schema(payload, &subject_name).await?)
+        } else {
+            Err(ParserError::MissingJsonSchemaConfiguration)
+        }
+    }
+
     pub fn parse_payload_to_string(&self, payload: &str) -> Vec<u8> {
         payload.as_bytes().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use apache_avro::Schema;
+    use async_trait::async_trait;
+
+    use crate::core::{
+        avro::{AvroError, ResolvedAvroSchema, SchemaProvider},
+        json_schema::{JsonSchemaError, JsonSchemaProvider, ResolvedJsonSchema},
+        protobuf::{ProtoSchemaProvider, ProtobufError, ResolvedProtoSchema},
+        schema_registry::SchemaRegistryError,
+        types::{ParserMode, ParserModes, RawKafkaRecord},
+    };
+
+    use super::{Parser, SubjectNameResolver};
+
+    const AVRO_SCHEMA_ID: i32 = 2;
+    const PROTO_SCHEMA_ID: i32 = 3;
+
+    /// Registry with an avro schema, a protobuf schema and any json schema id
+    struct MockSchemaRegistry;
+
+    #[async_trait]
+    impl SchemaProvider for MockSchemaRegistry {
+        async fn get_schema_by_id(&self, id: i32) -> Result<ResolvedAvroSchema, AvroError> {
+            if id == AVRO_SCHEMA_ID {
+                let schema = Schema::parse_str(r#"{"type": "string"}"#).unwrap();
+                return Ok(ResolvedAvroSchema::from(id, &schema, vec![]));
+            }
+            Err(AvroError::SchemaProvider(
+                format!("Unable to retrieve the schema id {id}"),
+                SchemaRegistryError::SchemaNotFound("Not an avro schema".into()),
+            ))
+        }
+        async fn get_schema_by_name(&self, name: &str) -> Result<ResolvedAvroSchema, AvroError> {
+            Err(AvroError::SchemaProvider(
+                format!("Unable to retrieve the schema {name}"),
+                SchemaRegistryError::SchemaNotFound("Not an avro schema".into()),
+            ))
+        }
+    }
+
+    #[async_trait]
+    impl ProtoSchemaProvider for MockSchemaRegistry {
+        async fn get_proto_schema_by_id(&self, id: i32) -> Result<ResolvedProtoSchema, ProtobufError> {
+            if id == PROTO_SCHEMA_ID {
+                return ResolvedProtoSchema::compile(id, r#"syntax = "proto3"; message Key { int32 id = 1; }"#, &[]);
+            }
+            Err(ProtobufError::SchemaProvider(
+                format!("Unable to retrieve the schema id {id}"),
+                SchemaRegistryError::SchemaNotFound("Not a protobuf schema".into()),
+            ))
+        }
+        async fn get_proto_schema_by_name(&self, name: &str) -> Result<ResolvedProtoSchema, ProtobufError> {
+            Err(ProtobufError::SchemaProvider(
+                format!("Unable to retrieve the schema {name}"),
+                SchemaRegistryError::SchemaNotFound("Not a protobuf schema".into()),
+            ))
+        }
+    }
+
+    #[async_trait]
+    impl JsonSchemaProvider for MockSchemaRegistry {
+        async fn get_json_schema_by_id(&self, id: i32) -> Result<ResolvedJsonSchema, JsonSchemaError> {
+            ResolvedJsonSchema::compile(id, r#"{"type": "object"}"#)
+        }
+        async fn get_json_schema_by_name(&self, _name: &str) -> Result<ResolvedJsonSchema, JsonSchemaError> {
+            ResolvedJsonSchema::compile(1, r#"{"type": "object"}"#)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detect_json_schema_record() {
+        // the protobuf message indexes count of a json schema record is negative
+        let mut payload = vec![0x00, 0x00, 0x00, 0x00, 0x01];
+        payload.extend_from_slice(br#"{"name":"example"}"#);
+        let record = get_test_record(None, Some(payload));
+        let sut = Parser::new(Some(Arc::new(MockSchemaRegistry)), None, SubjectNameResolver::default());
+        let res = sut
+            .parse_from_kafka_record(&record, &ParserModes::default())
+            .await
+            .unwrap();
+        assert_eq!(res.payload.as_deref(), Some(r#"{"name":"example"}"#));
+        assert_eq!(res.schema_id, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_parse_avro_key_and_string_value() {
+        // avro string "key" with the schema registry header
+        let key = vec![0x00, 0x00, 0x00, 0x00, 0x02, 0x06, b'k', b'e', b'y'];
+        let record = get_test_record(Some(key), Some(b"value".to_vec()));
+        let modes = ParserModes {
+            key: Some(ParserMode::Avro),
+            value: Some(ParserMode::String),
+        };
+        let sut = Parser::new(Some(Arc::new(MockSchemaRegistry)), None, SubjectNameResolver::default());
+        let res = sut.parse_from_kafka_record(&record, &modes).await.unwrap();
+        assert_eq!(res.key.as_deref(), Some(r#""key""#));
+        assert_eq!(res.key_schema_id, Some(AVRO_SCHEMA_ID));
+        assert_eq!(res.payload.as_deref(), Some("value"));
+        assert_eq!(res.schema_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_detect_protobuf_key_schema_id() {
+        // message Key { id: 42 } with the schema registry header and the first message index
+        let key = vec![0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x08, 0x2a];
+        let record = get_test_record(Some(key), Some(b"value".to_vec()));
+        let sut = Parser::new(Some(Arc::new(MockSchemaRegistry)), None, SubjectNameResolver::default());
+        let res = sut
+            .parse_from_kafka_record(&record, &ParserModes::default())
+            .await
+            .unwrap();
+        assert_eq!(res.key.as_deref(), Some(r#"{"id":42}"#));
+        assert_eq!(res.key_schema_id, Some(PROTO_SCHEMA_ID));
+        assert_eq!(res.payload.as_deref(), Some("value"));
+        assert_eq!(res.schema_id, None);
+    }
+
+    fn get_test_record(key: Option<Vec<u8>>, payload: Option<Vec<u8>>) -> RawKafkaRecord {
+        RawKafkaRecord {
+            payload,
+            key,
+            topic: "topic".into(),
+            timestamp: None,
+            partition: 0,
+            offset: 0,
+            record_bytes: 0,
+            headers: vec![],
+        }
+    }
+}
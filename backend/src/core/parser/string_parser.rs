@@ -2,9 +2,22 @@ pub(super) fn parse_string(v: &[u8]) -> String {
     String::from_utf8_lossy(v).into_owned()
 }
 
+/// Serialize the record headers into a JSON array of [key, value] pairs.
+/// Kafka allows duplicated keys, so the headers are kept in the record order.
+pub(super) fn parse_headers(headers: &[(String, Option<Vec<u8>>)]) -> Option<String> {
+    if headers.is_empty() {
+        return None;
+    }
+    let headers: Vec<(String, Option<String>)> = headers
+        .iter()
+        .map(|(key, value)| (key.clone(), value.as_deref().map(parse_string)))
+        .collect();
+    serde_json::to_string(&headers).ok()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_string;
+    use super::{parse_headers, parse_string};
 
     #[test]
     fn parse_empty_array_to_string() {
@@ -19,4 +32,31 @@ mod tests {
         let res = parse_string(&vec);
         assert!(!res.is_empty())
     }
+
+    #[test]
+    fn parse_headers_to_json() {
+        // no headers
+        {
+            let res = parse_headers(&[]);
+            assert_eq!(res, None)
+        }
+        // headers with null values
+        {
+            let headers = vec![
+                ("traceId".to_string(), Some(b"abc".to_vec())),
+                ("empty".to_string(), None),
+            ];
+            let res = parse_headers(&headers);
+            assert_eq!(res, Some(r#"[["traceId","abc"],["empty",null]]"#.into()))
+        }
+        // duplicated keys are kept in order
+        {
+            let headers = vec![
+                ("retry".to_string(), Some(b"1".to_vec())),
+                ("retry".to_string(), Some(b"2".to_vec())),
+            ];
+            let res = parse_headers(&headers);
+            assert_eq!(res, Some(r#"[["retry","1"],["retry","2"]]"#.into()))
+        }
+    }
 }
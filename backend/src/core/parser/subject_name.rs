@@ -0,0 +1,133 @@
+use regex::Regex;
+
+use crate::core::{
+    configuration::{SubjectNameStrategy, SubjectNameStrategyConfig},
+    types::RecordPart,
+};
+
+use super::error::{ParserError, ParserResult};
+
+/// Retrieve the schema registry subject used to serialize the records of a topic
+#[derive(Default)]
+pub struct SubjectNameResolver {
+    default: SubjectNameStrategy,
+    topic_patterns: Vec<(Regex, SubjectNameStrategy)>,
+}
+
+impl SubjectNameResolver {
+    pub fn new(config: &SubjectNameStrategyConfig) -> ParserResult<Self> {
+        let topic_patterns = config
+            .topic_patterns
+            .iter()
+            .map(|p| {
+                Regex::new(&format!("^(?:{})$", p.pattern))
+                    .map(|regex| (regex, p.strategy))
+                    .map_err(|err| ParserError::SubjectName(format!("Invalid topic pattern {}: {err}", p.pattern)))
+            })
+            .collect::<ParserResult<Vec<_>>>()?;
+        Ok(Self {
+            default: config.default,
+            topic_patterns,
+        })
+    }
+
+    pub fn get_strategy(&self, topic: &str) -> SubjectNameStrategy {
+        self.topic_patterns
+            .iter()
+            .find(|(regex, _)| regex.is_match(topic))
+            .map(|(_, strategy)| *strategy)
+            .unwrap_or(self.default)
+    }
+
+    /// The record name is the fully qualified name of the record type to serialize.
+    /// It is required by the record name strategies and ignored by the topic name strategy
+    pub fn subject_name(&self, topic: &str, part: RecordPart, record_name: Option<&str>) -> ParserResult<String> {
+        let strategy = self.get_strategy(topic);
+        let record_name = || {
+            record_name.ok_or_else(|| {
+                ParserError::SubjectName(format!(
+                    "The record name is required to produce to {topic} with the {strategy:?} strategy"
+                ))
+            })
+        };
+        match strategy {
+            SubjectNameStrategy::Topic => Ok(part.subject_name(topic)),
+            SubjectNameStrategy::Record => Ok(record_name()?.to_string()),
+            SubjectNameStrategy::TopicRecord => Ok(format!("{topic}-{}", record_name()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        configuration::{SubjectNameStrategy, SubjectNameStrategyConfig, TopicSubjectNameStrategy},
+        types::RecordPart,
+    };
+
+    use super::SubjectNameResolver;
+
+    fn get_sut() -> SubjectNameResolver {
+        SubjectNameResolver::new(&SubjectNameStrategyConfig {
+            default: SubjectNameStrategy::Topic,
+            topic_patterns: vec![
+                TopicSubjectNameStrategy {
+                    pattern: "events\\..*".into(),
+                    strategy: SubjectNameStrategy::Record,
+                },
+                TopicSubjectNameStrategy {
+                    pattern: "audit".into(),
+                    strategy: SubjectNameStrategy::TopicRecord,
+                },
+            ],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_topic_name_strategy() {
+        let sut = get_sut();
+        assert_eq!(
+            sut.subject_name("orders", RecordPart::Value, None).unwrap(),
+            "orders-value"
+        );
+        assert_eq!(
+            sut.subject_name("orders", RecordPart::Key, Some("com.example.Order"))
+                .unwrap(),
+            "orders-key"
+        );
+        // patterns need to match the whole topic name
+        assert_eq!(
+            sut.subject_name("audit-logs", RecordPart::Value, None).unwrap(),
+            "audit-logs-value"
+        );
+    }
+
+    #[test]
+    fn test_record_name_strategies() {
+        let sut = get_sut();
+        assert_eq!(
+            sut.subject_name("events.orders", RecordPart::Value, Some("com.example.Order"))
+                .unwrap(),
+            "com.example.Order"
+        );
+        assert_eq!(
+            sut.subject_name("audit", RecordPart::Value, Some("com.example.Audit"))
+                .unwrap(),
+            "audit-com.example.Audit"
+        );
+        assert!(sut.subject_name("audit", RecordPart::Value, None).is_err());
+    }
+
+    #[test]
+    fn test_invalid_pattern_fails() {
+        let res = SubjectNameResolver::new(&SubjectNameStrategyConfig {
+            default: SubjectNameStrategy::Topic,
+            topic_patterns: vec![TopicSubjectNameStrategy {
+                pattern: "(".into(),
+                strategy: SubjectNameStrategy::Record,
+            }],
+        });
+        assert!(res.is_err());
+    }
+}
@@ -1,43 +1,101 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use rdkafka::producer::{BaseProducer, BaseRecord};
+use rdkafka::{
+    message::{Header, OwnedHeaders},
+    producer::{FutureProducer, FutureRecord},
+};
 
 use crate::core::{
     configuration::{build_kafka_client_config, ClusterConfig},
     parser::Parser,
-    types::ParserMode,
+    types::{ParserMode, RecordPart},
 };
 
-use super::{error::ProducerResult, record_parser::KafkaRecordParser};
+use super::{
+    error::ProducerResult,
+    record_parser::KafkaRecordParser,
+    types::{DeliveryReport, ProduceOptions},
+};
 
 pub struct KafkaProducer<P: KafkaRecordParser = Parser> {
-    producer: BaseProducer,
+    producer: FutureProducer,
     parser: Arc<P>,
+    timeout: Duration,
 }
 
 impl<P: KafkaRecordParser> KafkaProducer<P> {
-    pub fn new(cluster_config: &ClusterConfig, parser: Arc<P>) -> Self {
-        let producer: BaseProducer = build_kafka_client_config(cluster_config, None)
+    pub fn new(cluster_config: &ClusterConfig, parser: Arc<P>, timeout: Duration) -> Self {
+        let producer: FutureProducer = build_kafka_client_config(cluster_config, None)
             .create()
             .expect("Unable to create the consumer"); //todo: bubble up the error
-        Self { producer, parser }
+        Self {
+            producer,
+            parser,
+            timeout,
+        }
     }
     // Use a None value for tombstones
-    pub async fn produce(&self, topic: &str, key: &str, value: Option<&str>, mode: ParserMode) -> ProducerResult<()> {
-        let mut record = BaseRecord::to(topic).key(key);
+    pub async fn produce(
+        &self,
+        topic: &str,
+        key: &str,
+        value: Option<&str>,
+        mode: ParserMode,
+        options: &ProduceOptions,
+    ) -> ProducerResult<DeliveryReport> {
+        let key = self
+            .serialize(
+                key,
+                topic,
+                RecordPart::Key,
+                options.key_mode.unwrap_or(ParserMode::String),
+                options.key_record_name.as_deref(),
+            )
+            .await?;
+        let mut record = FutureRecord::to(topic).key(&key);
         let payload = if let Some(payload) = value {
-            match mode {
-                ParserMode::String => Some(self.parser.parse_to_string(payload)),
-                ParserMode::Avro => Some(self.parser.parse_to_avro(payload, topic).await?),
-            }
+            Some(
+                self.serialize(payload, topic, RecordPart::Value, mode, options.record_name.as_deref())
+                    .await?,
+            )
         } else {
             None
         };
-        if let Some(value) = payload {
-            record = record.payload(&value);
-            Ok(self.producer.send(record).map_err(|err| err.0)?)
-        } else {
-            Ok(self.producer.send(record).map_err(|err| err.0)?)
+        if let Some(value) = &payload {
+            record = record.payload(value);
+        }
+        if let Some(partition) = options.partition {
+            record = record.partition(partition);
+        }
+        if let Some(timestamp) = options.timestamp {
+            record = record.timestamp(timestamp);
+        }
+        if let Some(headers) = &options.headers {
+            let owned_headers = headers.iter().fold(OwnedHeaders::new(), |acc, (key, value)| {
+                acc.insert(Header {
+                    key,
+                    value: value.as_deref(),
+                })
+            });
+            record = record.headers(owned_headers);
+        }
+        let (partition, offset) = self.producer.send(record, self.timeout).await.map_err(|(err, _)| err)?;
+        Ok(DeliveryReport { partition, offset })
+    }
+
+    async fn serialize(
+        &self,
+        data: &str,
+        topic: &str,
+        part: RecordPart,
+        mode: ParserMode,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>> {
+        match mode {
+            ParserMode::String => Ok(self.parser.parse_to_string(data)),
+            ParserMode::Avro => self.parser.parse_to_avro(data, topic, part, record_name).await,
+            ParserMode::Protobuf => self.parser.parse_to_protobuf(data, topic, part, record_name).await,
+            ParserMode::Json => self.parser.parse_to_json_schema(data, topic, part, record_name).await,
         }
     }
 }
@@ -2,10 +2,16 @@ use rdkafka::error::KafkaError;
 
 use crate::core::parser::ParserError;
 
+#[derive(Debug)]
 pub enum ProducerError {
     MissingAvroConfiguration,
+    MissingProtobufConfiguration,
+    MissingJsonSchemaConfiguration,
     RDKafka(String),
     AvroParse(crate::core::avro::AvroError),
+    ProtobufParse(crate::core::protobuf::ProtobufError),
+    JsonSchemaParse(crate::core::json_schema::JsonSchemaError),
+    SubjectName(String),
 }
 
 pub type ProducerResult<T> = Result<T, ProducerError>;
@@ -20,7 +26,12 @@ impl From<ParserError> for ProducerError {
     fn from(value: ParserError) -> Self {
         match value {
             ParserError::MissingAvroConfiguration => ProducerError::MissingAvroConfiguration,
+            ParserError::MissingProtobufConfiguration => ProducerError::MissingProtobufConfiguration,
             ParserError::Avro(err) => ProducerError::AvroParse(err),
+            ParserError::MissingJsonSchemaConfiguration => ProducerError::MissingJsonSchemaConfiguration,
+            ParserError::Protobuf(err) => ProducerError::ProtobufParse(err),
+            ParserError::JsonSchema(err) => ProducerError::JsonSchemaParse(err),
+            ParserError::SubjectName(message) => ProducerError::SubjectName(message),
         }
     }
 }
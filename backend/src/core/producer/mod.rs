@@ -1,5 +1,7 @@
 mod client;
 mod error;
 mod record_parser;
+pub mod types;
 pub use client::KafkaProducer;
 pub use error::ProducerError;
+pub use types::{DeliveryReport, ProduceOptions};
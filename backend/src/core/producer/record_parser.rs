@@ -1,13 +1,33 @@
 use async_trait::async_trait;
 
-use crate::core::parser::Parser;
+use crate::core::{parser::Parser, types::RecordPart};
 
 use super::error::ProducerResult;
 
 #[async_trait]
 pub trait KafkaRecordParser {
     fn parse_to_string(&self, payload: &str) -> Vec<u8>;
-    async fn parse_to_avro(&self, payload: &str, topic_name: &str) -> ProducerResult<Vec<u8>>;
+    async fn parse_to_avro(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>>;
+    async fn parse_to_protobuf(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>>;
+    async fn parse_to_json_schema(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>>;
 }
 
 #[async_trait]
@@ -15,7 +35,37 @@ impl KafkaRecordParser for Parser {
     fn parse_to_string(&self, payload: &str) -> Vec<u8> {
         self.parse_payload_to_string(payload)
     }
-    async fn parse_to_avro(&self, payload: &str, topic_name: &str) -> ProducerResult<Vec<u8>> {
-        Ok(self.parse_payload_to_avro(payload, topic_name).await?)
+    async fn parse_to_avro(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>> {
+        Ok(self
+            .parse_payload_to_avro(payload, topic_name, part, record_name)
+            .await?)
+    }
+    async fn parse_to_protobuf(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>> {
+        Ok(self
+            .parse_payload_to_protobuf(payload, topic_name, part, record_name)
+            .await?)
+    }
+    async fn parse_to_json_schema(
+        &self,
+        payload: &str,
+        topic_name: &str,
+        part: RecordPart,
+        record_name: Option<&str>,
+    ) -> ProducerResult<Vec<u8>> {
+        Ok(self
+            .parse_payload_to_json_schema(payload, topic_name, part, record_name)
+            .await?)
     }
 }
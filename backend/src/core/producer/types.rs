@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::types::ParserMode;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProduceOptions {
+    /// Record headers as (key, value) pairs
+    pub headers: Option<Vec<(String, Option<String>)>>,
+    /// Target partition. If not specified the partitioner configured in the producer is used
+    pub partition: Option<i32>,
+    /// Record timestamp in ms. If not specified the current time is used
+    pub timestamp: Option<i64>,
+    /// Serialization of the record key. String if not specified
+    #[serde(rename = "keyMode")]
+    pub key_mode: Option<ParserMode>,
+    /// Fully qualified name of the value record type, required by the record name subject strategies
+    #[serde(rename = "recordName")]
+    pub record_name: Option<String>,
+    /// Fully qualified name of the key record type, required by the record name subject strategies
+    #[serde(rename = "keyRecordName")]
+    pub key_record_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReport {
+    pub partition: i32,
+    pub offset: i64,
+}
@@ -0,0 +1,14 @@
+use crate::core::schema_registry::SchemaRegistryError;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProtobufError {
+    SchemaProvider(String, SchemaRegistryError),
+    SchemaCompilation(String),
+    MissingSchema(String),
+    MessageNotFound(String),
+    InvalidProtobufHeader(String),
+    ParseProtobufValue(String),
+    ParseJsonValue(String),
+}
+
+pub type ProtobufResult<T> = std::result::Result<T, ProtobufError>;
@@ -0,0 +1,114 @@
+use prost::encoding::{decode_varint, encode_varint};
+
+use super::error::{ProtobufError, ProtobufResult};
+
+const MAGIC_BYTE: u8 = 0x00;
+
+/// Split a record serialized with the confluent protobuf serializer into
+/// schema id, message indexes and protobuf payload.
+/// The message indexes are a zig-zag varint encoded array, with the shortcut `0` for `[0]`
+pub(super) fn parse_record_header(raw: &[u8]) -> ProtobufResult<(i32, Vec<i32>, &[u8])> {
+    if raw.len() < 6 || raw[0] != MAGIC_BYTE {
+        return Err(ProtobufError::InvalidProtobufHeader(
+            "Supported protobuf messages should start with 0x00 follow by the schema id (4 bytes) and the message indexes"
+                .into(),
+        ));
+    }
+    let id = i32::from_be_bytes([raw[1], raw[2], raw[3], raw[4]]);
+    let mut buf = &raw[5..];
+    let invalid_indexes = |_| ProtobufError::InvalidProtobufHeader("Unable to read the message indexes".into());
+    let count = zigzag_decode(decode_varint(&mut buf).map_err(invalid_indexes)?);
+    // each index takes at least one byte
+    if count < 0 || count as u64 > buf.len() as u64 {
+        return Err(ProtobufError::InvalidProtobufHeader(format!(
+            "Invalid number of message indexes {count}"
+        )));
+    }
+    let indexes = if count == 0 {
+        vec![0]
+    } else {
+        let mut indexes = vec![];
+        for _ in 0..count {
+            indexes.push(zigzag_decode(decode_varint(&mut buf).map_err(invalid_indexes)?) as i32);
+        }
+        indexes
+    };
+    Ok((id, indexes, buf))
+}
+
+pub(super) fn build_record_header(schema_id: i32, indexes: &[i32]) -> Vec<u8> {
+    let mut res = vec![MAGIC_BYTE];
+    res.extend_from_slice(&schema_id.to_be_bytes());
+    if indexes == [0] {
+        res.push(0);
+    } else {
+        encode_varint(zigzag_encode(indexes.len() as i64), &mut res);
+        for i in indexes {
+            encode_varint(zigzag_encode(*i as i64), &mut res);
+        }
+    }
+    res
+}
+
+fn zigzag_decode(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
+
+fn zigzag_encode(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_record_header, parse_record_header};
+
+    #[test]
+    fn test_parse_header_first_message() {
+        let raw: Vec<u8> = vec![0x00, 0x00, 0x01, 0x86, 0xc5, 0x00, 0x08, 0x01];
+        let (id, indexes, payload) = parse_record_header(&raw).unwrap();
+        assert_eq!(id, 100037);
+        assert_eq!(indexes, vec![0]);
+        assert_eq!(payload, &[0x08, 0x01]);
+    }
+
+    #[test]
+    fn test_parse_header_nested_message() {
+        // 2 indexes: [1, 0]
+        let raw: Vec<u8> = vec![0x00, 0x00, 0x00, 0x00, 0x01, 0x04, 0x02, 0x00, 0x08, 0x01];
+        let (id, indexes, payload) = parse_record_header(&raw).unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(indexes, vec![1, 0]);
+        assert_eq!(payload, &[0x08, 0x01]);
+    }
+
+    #[test]
+    fn test_invalid_indexes_count_fails() {
+        // negative count, e.g. a json schema record starting with `{`
+        let raw: Vec<u8> = vec![0x00, 0x00, 0x00, 0x00, 0x01, b'{', b'}'];
+        assert!(parse_record_header(&raw).is_err());
+        // count larger than the remaining bytes
+        let raw: Vec<u8> = vec![0x00, 0x00, 0x00, 0x00, 0x01, 0xfe, 0xff, 0xff, 0xff, 0x0f, 0x02];
+        assert!(parse_record_header(&raw).is_err());
+    }
+
+    #[test]
+    fn test_missing_magic_byte_fails() {
+        let raw: Vec<u8> = vec![0x01, 0x00, 0x01, 0x86, 0xc5, 0x00, 0x00, 0x00];
+        assert!(parse_record_header(&raw).is_err());
+    }
+
+    #[test]
+    fn test_build_header_round_trip() {
+        assert_eq!(
+            build_record_header(100037, &[0]),
+            vec![0x00, 0x00, 0x01, 0x86, 0xc5, 0x00]
+        );
+        let raw = build_record_header(1, &[1, 0]);
+        let (id, indexes, payload) = parse_record_header(&[raw, vec![0x08]].concat())
+            .map(|(i, idx, p)| (i, idx, p.to_vec()))
+            .unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(indexes, vec![1, 0]);
+        assert_eq!(payload, vec![0x08]);
+    }
+}
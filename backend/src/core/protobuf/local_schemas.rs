@@ -0,0 +1,101 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use log::debug;
+use prost_reflect::{DescriptorPool, MessageDescriptor};
+use protox::Compiler;
+
+use crate::core::configuration::ProtobufConfig;
+
+use super::error::{ProtobufError, ProtobufResult};
+
+/// Protobuf schemas loaded from local `.desc` descriptor sets and `.proto` files
+pub struct LocalProtoSchemas {
+    pool: DescriptorPool,
+    topic_message_types: HashMap<String, String>,
+}
+
+impl LocalProtoSchemas {
+    pub fn load(config: &ProtobufConfig) -> ProtobufResult<Self> {
+        let (proto_files, descriptor_sets): (Vec<_>, Vec<_>) = config
+            .files
+            .iter()
+            .map(Path::new)
+            .partition(|p| p.extension().map(|e| e == "proto").unwrap_or(false));
+        // compile the .proto files using the file directories as include paths
+        let mut pool = if proto_files.is_empty() {
+            DescriptorPool::new()
+        } else {
+            let mut includes: Vec<_> = proto_files.iter().filter_map(|p| p.parent()).collect();
+            includes.dedup();
+            debug!("Compile local proto files {:?}", proto_files);
+            let mut compiler =
+                Compiler::new(includes).map_err(|err| ProtobufError::SchemaCompilation(err.to_string()))?;
+            compiler
+                .include_imports(true)
+                .open_files(&proto_files)
+                .map_err(|err| ProtobufError::SchemaCompilation(err.to_string()))?;
+            compiler.descriptor_pool()
+        };
+        for descriptor_set in descriptor_sets {
+            debug!("Load local descriptor set {:?}", descriptor_set);
+            let bytes = fs::read(descriptor_set).map_err(|err| {
+                ProtobufError::SchemaCompilation(format!("Unable to read {}: {err}", descriptor_set.display()))
+            })?;
+            pool.decode_file_descriptor_set(bytes.as_slice()).map_err(|err| {
+                ProtobufError::SchemaCompilation(format!("Invalid descriptor set {}: {err}", descriptor_set.display()))
+            })?;
+        }
+        Ok(Self {
+            pool,
+            topic_message_types: config.topic_message_types.clone(),
+        })
+    }
+
+    /// Message to use for the topic. None if the topic has no local schema
+    pub fn get_message(&self, topic: &str) -> ProtobufResult<Option<MessageDescriptor>> {
+        match self.topic_message_types.get(topic) {
+            Some(message_type) => self
+                .pool
+                .get_message_by_name(message_type)
+                .map(Some)
+                .ok_or_else(|| ProtobufError::MessageNotFound(format!("Message {message_type} not found"))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, env::temp_dir, fs};
+
+    use crate::core::configuration::ProtobufConfig;
+
+    use super::LocalProtoSchemas;
+
+    #[test]
+    fn test_load_proto_files() {
+        // arrange
+        let mut dir = temp_dir();
+        dir.push(format!("insulator2-proto-{}", rand::random::<usize>()));
+        fs::create_dir_all(&dir).unwrap();
+        let proto_path = dir.join("test.proto");
+        fs::write(
+            &proto_path,
+            "syntax = \"proto3\"; package test; message Example { string name = 1; }",
+        )
+        .unwrap();
+        let config = ProtobufConfig {
+            files: vec![proto_path.to_str().unwrap().into()],
+            topic_message_types: HashMap::from([
+                ("topic".into(), "test.Example".into()),
+                ("wrong".into(), "test.Missing".into()),
+            ]),
+        };
+        // act
+        let sut = LocalProtoSchemas::load(&config).unwrap();
+        // assert
+        assert_eq!(sut.get_message("topic").unwrap().unwrap().full_name(), "test.Example");
+        assert!(sut.get_message("another_topic").unwrap().is_none());
+        assert!(sut.get_message("wrong").is_err());
+    }
+}
@@ -0,0 +1,12 @@
+mod error;
+mod helpers;
+mod local_schemas;
+mod proto_schema;
+mod protobuf_parser;
+mod schema_provider;
+
+pub use error::ProtobufError;
+pub use local_schemas::LocalProtoSchemas;
+pub use proto_schema::ResolvedProtoSchema;
+pub use protobuf_parser::ProtobufParser;
+pub use schema_provider::ProtoSchemaProvider;
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use prost_reflect::{FileDescriptor, MessageDescriptor};
+use protox::{
+    file::{ChainFileResolver, File, FileResolver, GoogleFileResolver},
+    Compiler,
+};
+
+use super::error::{ProtobufError, ProtobufResult};
+
+/// Compiled protobuf schema retrieved from the schema registry
+#[derive(Clone, Debug)]
+pub struct ResolvedProtoSchema {
+    pub id: i32,
+    pub file: FileDescriptor,
+}
+
+impl ResolvedProtoSchema {
+    /// Compile the schema resolving its imports with the (import name, source) pairs,
+    /// e.g. the schemas referenced in the schema registry
+    pub fn compile(id: i32, source: &str, imports: &[(String, String)]) -> ProtobufResult<Self> {
+        const SCHEMA_FILE_NAME: &str = "schema.proto";
+        let mut files: HashMap<_, _> = imports.iter().cloned().collect();
+        files.insert(SCHEMA_FILE_NAME.into(), source.into());
+        let mut resolver = ChainFileResolver::new();
+        resolver.add(SourceFileResolver { files });
+        resolver.add(GoogleFileResolver::new());
+        let mut compiler = Compiler::with_file_resolver(resolver);
+        compiler
+            .open_file(SCHEMA_FILE_NAME)
+            .map_err(|err| ProtobufError::SchemaCompilation(format!("Unable to compile the schema {id}: {err}")))?;
+        let file = compiler
+            .descriptor_pool()
+            .get_file_by_name(SCHEMA_FILE_NAME)
+            .ok_or_else(|| ProtobufError::SchemaCompilation(format!("Unable to compile the schema {id}")))?;
+        Ok(Self { id, file })
+    }
+
+    /// Retrieve the message using the indexes in the record header.
+    /// The first index is the top level message in the file, the others are the nested messages
+    pub fn get_message(&self, indexes: &[i32]) -> ProtobufResult<MessageDescriptor> {
+        let not_found = || ProtobufError::MessageNotFound(format!("Message {indexes:?} not found in schema {}", self.id));
+        let (first, nested) = indexes.split_first().ok_or_else(not_found)?;
+        let mut message = self.file.messages().nth(*first as usize).ok_or_else(not_found)?;
+        for i in nested {
+            let child = message.child_messages().nth(*i as usize).ok_or_else(not_found)?;
+            message = child;
+        }
+        Ok(message)
+    }
+
+    /// Retrieve the record header indexes of the message with the specified fully qualified name
+    pub fn get_message_indexes(&self, full_name: &str) -> ProtobufResult<Vec<i32>> {
+        fn find(messages: impl Iterator<Item = MessageDescriptor>, full_name: &str) -> Option<Vec<i32>> {
+            for (i, message) in messages.enumerate() {
+                if message.full_name() == full_name {
+                    return Some(vec![i as i32]);
+                }
+                if let Some(mut nested) = find(message.child_messages(), full_name) {
+                    nested.insert(0, i as i32);
+                    return Some(nested);
+                }
+            }
+            None
+        }
+        find(self.file.messages(), full_name)
+            .ok_or_else(|| ProtobufError::MessageNotFound(format!("Message {full_name} not found in schema {}", self.id)))
+    }
+}
+
+/// Source of the files by import name
+struct SourceFileResolver {
+    files: HashMap<String, String>,
+}
+
+impl FileResolver for SourceFileResolver {
+    fn open_file(&self, name: &str) -> Result<File, protox::Error> {
+        match self.files.get(name) {
+            Some(source) => File::from_source(name, source),
+            None => Err(protox::Error::file_not_found(name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResolvedProtoSchema;
+
+    const TEST_SCHEMA: &str = r#"
+        syntax = "proto3";
+        package test;
+        import "google/protobuf/timestamp.proto";
+
+        message First {
+            string name = 1;
+        }
+
+        message Second {
+            message Nested {
+                int32 value = 1;
+            }
+            Nested nested = 1;
+            google.protobuf.Timestamp created_at = 2;
+        }
+    "#;
+
+    #[test]
+    fn test_get_message_by_indexes() {
+        let schema = ResolvedProtoSchema::compile(1, TEST_SCHEMA, &[]).unwrap();
+        assert_eq!(schema.get_message(&[0]).unwrap().full_name(), "test.First");
+        assert_eq!(schema.get_message(&[1]).unwrap().full_name(), "test.Second");
+        assert_eq!(schema.get_message(&[1, 0]).unwrap().full_name(), "test.Second.Nested");
+        assert!(schema.get_message(&[2]).is_err());
+        assert!(schema.get_message(&[]).is_err());
+    }
+
+    #[test]
+    fn test_get_message_indexes() {
+        let schema = ResolvedProtoSchema::compile(1, TEST_SCHEMA, &[]).unwrap();
+        assert_eq!(schema.get_message_indexes("test.First").unwrap(), vec![0]);
+        assert_eq!(schema.get_message_indexes("test.Second").unwrap(), vec![1]);
+        assert_eq!(schema.get_message_indexes("test.Second.Nested").unwrap(), vec![1, 0]);
+        assert!(schema.get_message_indexes("test.Missing").is_err());
+    }
+
+    #[test]
+    fn test_compile_with_imports() {
+        let imported = r#"
+            syntax = "proto3";
+            package common;
+            message Address {
+                string street = 1;
+            }
+        "#;
+        let schema = r#"
+            syntax = "proto3";
+            package test;
+            import "common/address.proto";
+            message User {
+                common.Address address = 1;
+            }
+        "#;
+        assert!(ResolvedProtoSchema::compile(1, schema, &[]).is_err());
+        let res = ResolvedProtoSchema::compile(1, schema, &[("common/address.proto".into(), imported.into())]).unwrap();
+        let user = res.get_message(&[0]).unwrap();
+        assert_eq!(user.full_name(), "test.User");
+        assert_eq!(
+            user.get_field_by_name("address")
+                .unwrap()
+                .kind()
+                .as_message()
+                .unwrap()
+                .full_name(),
+            "common.Address"
+        );
+    }
+
+    #[test]
+    fn test_invalid_schema_fails() {
+        assert!(ResolvedProtoSchema::compile(1, "message {", &[]).is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use prost::Message;
+use prost_reflect::{DynamicMessage, MessageDescriptor, SerializeOptions};
+
+use crate::core::types::RecordPart;
+
+use super::{
+    error::{ProtobufError, ProtobufResult},
+    helpers::{build_record_header, parse_record_header},
+    local_schemas::LocalProtoSchemas,
+    schema_provider::ProtoSchemaProvider,
+};
+
+pub struct ProtobufParser<S: ProtoSchemaProvider> {
+    schema_provider: Option<Arc<S>>,
+    local_schemas: Option<LocalProtoSchemas>,
+}
+
+impl<S: ProtoSchemaProvider> ProtobufParser<S> {
+    pub fn new(schema_provider: Option<Arc<S>>, local_schemas: Option<LocalProtoSchemas>) -> Self {
+        Self {
+            schema_provider,
+            local_schemas,
+        }
+    }
+
+    /// Decode the record to JSON. The local schemas configured for the topic values take precedence
+    /// over the schema registry. Returns the schema id if the record uses the confluent wire format.
+    pub async fn protobuf_to_json(
+        &self,
+        raw: &[u8],
+        topic: &str,
+        part: RecordPart,
+    ) -> ProtobufResult<(Option<i32>, String)> {
+        if let Some(message) = self.get_local_message(topic, part)? {
+            // plain protobuf records can't start with 0x00 since it is not a valid tag
+            return match parse_record_header(raw) {
+                Ok((id, _, payload)) => Ok((Some(id), decode(message, payload)?)),
+                Err(_) => Ok((None, decode(message, raw)?)),
+            };
+        }
+        let schema_provider = self.get_schema_provider(topic)?;
+        let (id, indexes, payload) = parse_record_header(raw)?;
+        let schema = schema_provider.get_proto_schema_by_id(id).await?;
+        let message = schema.get_message(&indexes)?;
+        Ok((Some(id), decode(message, payload)?))
+    }
+
+    /// Encode the JSON to protobuf with the local schema configured for the topic, if any
+    pub fn json_to_local_protobuf(&self, json: &str, topic: &str, part: RecordPart) -> ProtobufResult<Option<Vec<u8>>> {
+        match self.get_local_message(topic, part)? {
+            Some(message) => Ok(Some(encode(message, json)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Encode the JSON to protobuf with the confluent wire format, using the last version of the subject schema.
+    /// The message is retrieved by fully qualified name if the record name is specified, otherwise
+    /// the first message of the schema is used
+    pub async fn json_to_protobuf(
+        &self,
+        json: &str,
+        subject_name: &str,
+        record_name: Option<&str>,
+    ) -> ProtobufResult<Vec<u8>> {
+        let schema_provider = self.schema_provider.as_ref().ok_or_else(|| {
+            ProtobufError::MissingSchema(format!(
+                "Unable to retrieve the schema {subject_name}, no schema registry available"
+            ))
+        })?;
+        let schema = schema_provider.get_proto_schema_by_name(subject_name).await?;
+        let indexes = match record_name {
+            Some(name) => schema.get_message_indexes(name)?,
+            None => vec![0],
+        };
+        let message = schema.get_message(&indexes)?;
+        let mut res = build_record_header(schema.id, &indexes);
+        res.append(&mut encode(message, json)?);
+        Ok(res)
+    }
+
+    /// Local schemas are only configured for the topic values
+    fn get_local_message(&self, topic: &str, part: RecordPart) -> ProtobufResult<Option<MessageDescriptor>> {
+        match (&self.local_schemas, part) {
+            (Some(local_schemas), RecordPart::Value) => local_schemas.get_message(topic),
+            _ => Ok(None),
+        }
+    }
+
+    fn get_schema_provider(&self, topic: &str) -> ProtobufResult<&Arc<S>> {
+        self.schema_provider.as_ref().ok_or_else(|| {
+            ProtobufError::MissingSchema(format!(
+                "No local schema configured for the topic {topic} and no schema registry available"
+            ))
+        })
+    }
+}
+
+fn decode(message: MessageDescriptor, payload: &[u8]) -> ProtobufResult<String> {
+    let record =
+        DynamicMessage::decode(message, payload).map_err(|err| ProtobufError::ParseProtobufValue(err.to_string()))?;
+    let mut serializer = serde_json::Serializer::new(vec![]);
+    record
+        .serialize_with_options(&mut serializer, &SerializeOptions::new().skip_default_fields(false))
+        .map_err(|err| ProtobufError::ParseJsonValue(err.to_string()))?;
+    String::from_utf8(serializer.into_inner()).map_err(|err| ProtobufError::ParseJsonValue(err.to_string()))
+}
+
+fn encode(message: MessageDescriptor, json: &str) -> ProtobufResult<Vec<u8>> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let record = DynamicMessage::deserialize(message, &mut deserializer)
+        .map_err(|err| ProtobufError::ParseJsonValue(err.to_string()))?;
+    deserializer
+        .end()
+        .map_err(|err| ProtobufError::ParseJsonValue(err.to_string()))?;
+    Ok(record.encode_to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use async_trait::async_trait;
+    use serde_json::Value as JsonValue;
+
+    use crate::core::{
+        protobuf::{error::ProtobufResult, ProtoSchemaProvider, ProtobufParser, ResolvedProtoSchema},
+        types::RecordPart,
+    };
+
+    const TEST_SCHEMA: &str = r#"
+        syntax = "proto3";
+        package test;
+
+        message Example {
+            string name = 1;
+            int64 count = 2;
+            repeated Item items = 3;
+            message Item {
+                bool enabled = 1;
+            }
+        }
+    "#;
+
+    struct MockSchemaRegistry {
+        schema: String,
+    }
+
+    #[async_trait]
+    impl ProtoSchemaProvider for MockSchemaRegistry {
+        async fn get_proto_schema_by_id(&self, id: i32) -> ProtobufResult<ResolvedProtoSchema> {
+            ResolvedProtoSchema::compile(id, &self.schema, &[])
+        }
+        async fn get_proto_schema_by_name(&self, _name: &str) -> ProtobufResult<ResolvedProtoSchema> {
+            ResolvedProtoSchema::compile(123, &self.schema, &[])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parsing_loop() {
+        // arrange
+        let sut = ProtobufParser::new(
+            Some(Arc::new(MockSchemaRegistry {
+                schema: TEST_SCHEMA.into(),
+            })),
+            None,
+        );
+        let json_in = r#"{"name":"example","count":"10","items":[{"enabled":true},{"enabled":false}]}"#;
+        // act
+        let raw = sut.json_to_protobuf(json_in, "topic-value", None).await.unwrap();
+        let (id, json_out) = sut.protobuf_to_json(&raw, "topic", RecordPart::Value).await.unwrap();
+        // assert
+        assert_eq!(&raw[..6], &[0x00, 0x00, 0x00, 0x00, 0x7b, 0x00]);
+        assert_eq!(id, Some(123));
+        assert_eq!(
+            JsonValue::from_str(&json_out).unwrap(),
+            JsonValue::from_str(json_in).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_produce_with_record_name() {
+        // arrange
+        let sut = ProtobufParser::new(
+            Some(Arc::new(MockSchemaRegistry {
+                schema: TEST_SCHEMA.into(),
+            })),
+            None,
+        );
+        let json_in = r#"{"enabled":true}"#;
+        // act
+        let raw = sut
+            .json_to_protobuf(json_in, "test.Example.Item", Some("test.Example.Item"))
+            .await
+            .unwrap();
+        // assert
+        assert_eq!(&raw[..8], &[0x00, 0x00, 0x00, 0x00, 0x7b, 0x04, 0x00, 0x00]);
+        assert!(sut
+            .json_to_protobuf(json_in, "test.Missing", Some("test.Missing"))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_missing_schema_registry() {
+        let sut = ProtobufParser::<MockSchemaRegistry>::new(None, None);
+        assert!(sut.json_to_protobuf("{}", "topic-value", None).await.is_err());
+        assert!(sut
+            .protobuf_to_json(&[0x00, 0x00, 0x00, 0x00, 0x01, 0x00], "topic", RecordPart::Key)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::core::schema_registry::CachedSchemaRegistry;
+
+use super::{
+    error::{ProtobufError, ProtobufResult},
+    ResolvedProtoSchema,
+};
+
+#[async_trait]
+pub trait ProtoSchemaProvider: Send + Sync {
+    async fn get_proto_schema_by_id(&self, id: i32) -> ProtobufResult<ResolvedProtoSchema>;
+    async fn get_proto_schema_by_name(&self, name: &str) -> ProtobufResult<ResolvedProtoSchema>;
+}
+
+#[async_trait]
+impl ProtoSchemaProvider for CachedSchemaRegistry {
+    async fn get_proto_schema_by_id(&self, id: i32) -> ProtobufResult<ResolvedProtoSchema> {
+        self.get_proto_schema_by_id(id)
+            .await
+            .map_err(|err| ProtobufError::SchemaProvider(format!("Unable to retrieve the schema id {id}"), err))
+    }
+    async fn get_proto_schema_by_name(&self, name: &str) -> ProtobufResult<ResolvedProtoSchema> {
+        self.get_last_proto_schema(name)
+            .await
+            .map_err(|err| ProtobufError::SchemaProvider(format!("Unable to retrieve the schema {name}"), err))
+    }
+}
@@ -14,6 +14,7 @@ impl From<SqlError> for StoreError {
         StoreError::SqlError(match error {
             SqlError::SqliteFailure(ffi::Error { code, .. }, ..) => match code {
                 rusqlite::ErrorCode::OperationInterrupted => "Operation timed out".into(),
+                rusqlite::ErrorCode::DiskFull => "The record store reached the maximum size".into(),
                 _ => format!("{error} {code:?}"),
             },
             _ => error.to_string(),
@@ -7,5 +7,5 @@ pub mod types;
 
 pub use error::StoreError;
 pub use query::{QueryResultRow, QueryResultRowItem};
-pub use sqlite_store::SqliteStore;
+pub use sqlite_store::{RecordStore, SqliteStore};
 pub use topic_store::TopicStore;
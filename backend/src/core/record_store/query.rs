@@ -22,6 +22,14 @@ impl Query {
     pub const TIMESTAMP: &'static str = "timestamp";
     pub const KEY: &'static str = "key";
     pub const PAYLOAD: &'static str = "payload";
+    #[cfg(test)]
+    pub const TOPIC: &'static str = "topic";
+    #[cfg(test)]
+    pub const HEADERS: &'static str = "headers";
+    #[cfg(test)]
+    pub const SCHEMA_ID: &'static str = "schema_id";
+    #[cfg(test)]
+    pub const KEY_SCHEMA_ID: &'static str = "key_schema_id";
     pub const SELECT_ALL_WITH_OFFSET_LIMIT_QUERY: &'static str =
         "SELECT * FROM {:topic} ORDER BY timestamp desc LIMIT {:limit} OFFSET {:offset}";
 
@@ -1,6 +1,8 @@
 use core::time;
 use std::{
     collections::HashMap,
+    fs,
+    path::Path,
     time::{Duration, Instant},
 };
 
@@ -11,13 +13,16 @@ use r2d2_sqlite::SqliteConnectionManager;
 use rusqlite::{backup::Backup, named_params, Connection, OpenFlags};
 
 use super::{
-    error::StoreResult,
+    error::{StoreError, StoreResult},
     query::{Query, QueryResultRow},
+    types::StoredTopic,
     QueryResultRowItem,
 };
 
 pub trait RecordStore {
     fn create_or_replace_topic_table(&self, cluster_id: &str, topic_name: &str, compacted: bool) -> StoreResult<()>;
+    /// Number of records already stored for the topic, None if the topic table doesn't exist
+    fn open_topic_table(&self, cluster_id: &str, topic_name: &str) -> StoreResult<Option<usize>>;
     fn query_records(&self, query: &Query, timeout: Option<Duration>) -> StoreResult<Vec<QueryResultRow>>;
     fn insert_record(&self, cluster_id: &str, topic_name: &str, record: &ParsedKafkaRecord) -> StoreResult<()>;
     fn destroy(&self, cluster_id: &str, topic_name: &str) -> StoreResult<()>;
@@ -80,47 +85,44 @@ impl RecordStore for SqliteStore {
         let connection = self.pool.get().unwrap();
         self.destroy(cluster_id, topic_name)?;
         connection
-            .execute(
-                format!(
-                    "CREATE TABLE {} (
-                        payload      TEXT,
-                        key          TEXT {},
-                        timestamp    NUMBER,
-                        partition    NUMBER NON NULL,
-                        offset       NUMBER NON NULL,
-                        schema_id    NUMBER,
-                        record_bytes NUMBER,
-                    PRIMARY KEY (partition, offset))",
-                    Self::get_table_name(cluster_id, topic_name),
-                    match compacted {
-                        true => "UNIQUE",
-                        false => "",
-                    }
-                )
-                .as_str(),
-                [],
-            )
+            .execute(Self::create_table_query(cluster_id, topic_name, compacted).as_str(), [])
             .unwrap_or_else(|e| panic!("Unable to create the table for {cluster_id} {topic_name} {e:?}"));
         Ok(())
     }
 
+    fn open_topic_table(&self, cluster_id: &str, topic_name: &str) -> StoreResult<Option<usize>> {
+        if self.get_table_sql(cluster_id, topic_name)?.is_none() {
+            return Ok(None);
+        }
+        let connection = self.pool.get().unwrap();
+        let records_count = connection.query_row(
+            format!("SELECT count(*) FROM {}", Self::get_table_name(cluster_id, topic_name)).as_str(),
+            [],
+            |row| row.get::<_, i64>(0),
+        )?;
+        Ok(Some(records_count as usize))
+    }
+
     fn insert_record(&self, cluster_id: &str, topic_name: &str, record: &ParsedKafkaRecord) -> StoreResult<()> {
         let connection = self.pool.get().unwrap();
         connection.execute(
             format!(
-                "INSERT OR REPLACE INTO {} (payload, key, timestamp, partition, offset, schema_id, record_bytes) 
-                VALUES (:payload, :key, :timestamp, :partition, :offset, :schema_id, :record_bytes)",
+                "INSERT OR REPLACE INTO {} (payload, key, topic, timestamp, partition, offset, schema_id, key_schema_id, record_bytes, headers) 
+                VALUES (:payload, :key, :topic, :timestamp, :partition, :offset, :schema_id, :key_schema_id, :record_bytes, :headers)",
                 Self::get_table_name(cluster_id, topic_name)
             )
             .as_str(),
             named_params! {
                 ":payload": &record.payload,
                 ":key": &record.key,
+                ":topic": &record.topic,
                 ":timestamp": &record.timestamp,
                 ":partition": &record.partition,
                 ":offset": &record.offset,
                 ":schema_id": &record.schema_id,
+                ":key_schema_id": &record.key_schema_id,
                 ":record_bytes": &record.record_bytes,
+                ":headers": &record.headers,
             },
         )?;
         Ok(())
@@ -139,6 +141,12 @@ impl RecordStore for SqliteStore {
 }
 
 impl SqliteStore {
+    const PAGE_SIZE: u64 = 4096;
+    /// Nullable columns added to the topic tables after the introduction of the on disk store.
+    /// The tables stored by previous versions are migrated when the store is opened
+    const ADDED_COLUMNS: [(&'static str, &'static str); 1] = [("key_schema_id", "NUMBER")];
+
+    /// In memory store. All the records are lost when the store is dropped
     pub fn new(timeout: Duration) -> Self {
         let file_name = format!("file::memory{}:?cache=shared&mode=memory", rand::random::<usize>());
         let flags_r = OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX | OpenFlags::SQLITE_OPEN_URI;
@@ -147,7 +155,7 @@ impl SqliteStore {
             .with_init(|conn| {
                 conn.pragma_update(None, "journal_mode", "OFF").unwrap();
                 conn.pragma_update(None, "synchronous", "OFF").unwrap();
-                conn.pragma_update(None, "page_size", "4096").unwrap();
+                conn.pragma_update(None, "page_size", Self::PAGE_SIZE).unwrap();
                 conn.pragma_update(None, "cache_size", "16384").unwrap();
                 conn.pragma_update(None, "locking_mode", "NORMAL").unwrap();
                 conn.pragma_update(None, "read_uncommitted", "ON").unwrap();
@@ -160,10 +168,108 @@ impl SqliteStore {
         SqliteStore { pool, timeout }
     }
 
+    /// On disk store. The database file is created if it doesn't exist,
+    /// otherwise the tables already stored are reused.
+    /// Inserts fail once the database file reaches `max_size_mb`
+    pub fn open(db_path: &Path, max_size_mb: Option<u64>, timeout: Duration) -> StoreResult<Self> {
+        debug!("Open record store at {:?}", db_path);
+        if let Some(parent) = db_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let flags_rw =
+            OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        let max_page_count = max_size_mb.map(|mb| mb * 1024 * 1024 / Self::PAGE_SIZE);
+        let manager = SqliteConnectionManager::file(db_path)
+            .with_flags(flags_rw)
+            .with_init(move |conn| {
+                conn.pragma_update(None, "journal_mode", "WAL")?;
+                conn.pragma_update(None, "synchronous", "NORMAL")?;
+                conn.pragma_update(None, "page_size", Self::PAGE_SIZE)?;
+                conn.pragma_update(None, "cache_size", "16384")?;
+                conn.pragma_update(None, "busy_timeout", "5000")?;
+                if let Some(max_page_count) = max_page_count {
+                    conn.pragma_update(None, "max_page_count", max_page_count)?;
+                }
+                Ok(())
+            });
+        let pool = r2d2::Pool::builder()
+            .max_size(20)
+            .build(manager)
+            .map_err(|err| StoreError::SqlError(format!("Unable to open the record store {db_path:?}: {err}")))?;
+        let store = SqliteStore { pool, timeout };
+        store.migrate_topic_tables()?;
+        Ok(store)
+    }
+
+    /// Add the columns missing in the topic tables stored by previous versions
+    fn migrate_topic_tables(&self) -> StoreResult<()> {
+        let connection = self.pool.get().unwrap();
+        let mut stmt = connection.prepare("SELECT name FROM sqlite_master WHERE type = 'table'")?;
+        let table_names = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        for table_name in table_names {
+            let mut stmt = connection.prepare("SELECT name FROM pragma_table_info(:table_name)")?;
+            let columns = stmt
+                .query_map(named_params! {":table_name": &table_name}, |row| {
+                    row.get::<_, String>(0)
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            for (column, column_type) in Self::ADDED_COLUMNS {
+                if !columns.iter().any(|c| c == column) {
+                    debug!("Add column {} to the table {}", column, table_name);
+                    connection.execute(
+                        format!("ALTER TABLE '{table_name}' ADD COLUMN {column} {column_type}").as_str(),
+                        [],
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// List the topic tables stored for the specified cluster
+    pub fn list_topic_tables(&self, cluster_id: &str) -> StoreResult<Vec<StoredTopic>> {
+        let connection = self.pool.get().unwrap();
+        let prefix = format!("[{cluster_id}].[");
+        let mut stmt = connection.prepare(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND substr(name, 1, length(:prefix)) = :prefix ORDER BY name",
+        )?;
+        let table_names = stmt
+            .query_map(named_params! {":prefix": &prefix}, |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut stored_topics = Vec::with_capacity(table_names.len());
+        for table_name in table_names {
+            let topic_name = table_name[prefix.len()..table_name.len() - 1].to_string();
+            let records_count = connection.query_row(
+                format!("SELECT count(*) FROM {}", Self::get_table_name(cluster_id, &topic_name)).as_str(),
+                [],
+                |row| row.get::<_, i64>(0),
+            )?;
+            stored_topics.push(StoredTopic {
+                topic_name,
+                records_count: records_count as usize,
+            });
+        }
+        Ok(stored_topics)
+    }
+
+    /// SQL used to create the topic table, None if the table doesn't exist
+    fn get_table_sql(&self, cluster_id: &str, topic_name: &str) -> StoreResult<Option<String>> {
+        let connection = self.pool.get().unwrap();
+        let mut stmt = connection.prepare("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = :name")?;
+        let sql = stmt
+            .query_map(
+                named_params! {":name": format!("[{cluster_id}].[{topic_name}]")},
+                |row| row.get::<_, String>(0),
+            )?
+            .next()
+            .transpose()?;
+        Ok(sql)
+    }
+
     #[cfg(test)]
     fn get_size(&self, query: &Query) -> StoreResult<usize> {
-        use super::error::StoreError;
-
         let connection = self.pool.get().unwrap();
         let mut stmt = connection.prepare(format!("SELECT count(*) FROM ({})", Self::parse_query(query)).as_str())?;
         let rows: Vec<_> = stmt.query_map([], |row| row.get::<_, i64>(0))?.collect();
@@ -214,6 +320,28 @@ impl SqliteStore {
         }
     }
 
+    fn create_table_query(cluster_id: &str, topic_name: &str, compacted: bool) -> String {
+        format!(
+            "CREATE TABLE {} (
+                payload      TEXT,
+                key          TEXT,
+                topic        TEXT NOT NULL,
+                timestamp    NUMBER,
+                partition    NUMBER NON NULL,
+                offset       NUMBER NON NULL,
+                schema_id    NUMBER,
+                key_schema_id NUMBER,
+                record_bytes NUMBER,
+                headers      TEXT,
+            PRIMARY KEY (topic, partition, offset){})",
+            Self::get_table_name(cluster_id, topic_name),
+            match compacted {
+                true => ", UNIQUE (topic, key)",
+                false => "",
+            }
+        )
+    }
+
     fn get_table_name(cluster_id: &str, topic_name: &str) -> String {
         format!("\'[{cluster_id}].[{topic_name}]\'")
     }
@@ -222,7 +350,7 @@ impl SqliteStore {
 #[cfg(test)]
 mod tests {
     use crate::core::{
-        record_store::{error::StoreError, sqlite_store::Query, QueryResultRow},
+        record_store::{error::StoreError, sqlite_store::Query, types::StoredTopic, QueryResultRow},
         types::ParsedKafkaRecord,
     };
     use std::{
@@ -278,7 +406,7 @@ mod tests {
             .unwrap();
         // assert
         assert_eq!(records_back.len(), 1);
-        assert_eq!(parse_row(&records_back[0], &test_record.topic), test_record);
+        assert_eq!(parse_row(&records_back[0]), test_record);
     }
 
     #[tokio::test]
@@ -324,7 +452,7 @@ mod tests {
                 .unwrap();
             // assert
             assert_eq!(records_back.len(), 1);
-            assert_eq!(parse_row(&records_back[0], &test_record2.topic), test_record2);
+            assert_eq!(parse_row(&records_back[0]), test_record2);
         }
         // non compacted table should persist all the data
         {
@@ -338,7 +466,7 @@ mod tests {
                 .unwrap();
             // assert
             assert_eq!(records_back.len(), 2);
-            assert_eq!(parse_row(&records_back[1], &test_record2.topic), test_record2);
+            assert_eq!(parse_row(&records_back[1]), test_record2);
         }
     }
 
@@ -397,6 +525,150 @@ mod tests {
         assert_eq!(table_size, 2);
     }
 
+    #[tokio::test]
+    async fn test_insert_records_from_multiple_topics() {
+        // arrange
+        let (cluster_id, consumer_name) = ("cluster_id_example", "^topic_.*");
+        let db = SqliteStore::new(Duration::from_secs(10));
+        db.create_or_replace_topic_table(cluster_id, consumer_name, true)
+            .expect("Unable to create the table");
+        let record1 = get_test_record("topic_1", 0);
+        let record2 = get_test_record("topic_2", 0);
+        // act
+        // records with the same partition, offset and key from different topics should not collide
+        db.insert_record(cluster_id, consumer_name, &record1).unwrap();
+        db.insert_record(cluster_id, consumer_name, &record2).unwrap();
+        let records_back = db
+            .query_records(
+                &Query {
+                    cluster_id: cluster_id.into(),
+                    topic_name: consumer_name.into(),
+                    limit: -1,
+                    offset: 0,
+                    query_template: "SELECT * FROM {:topic} ORDER BY topic".into(),
+                },
+                None,
+            )
+            .unwrap();
+        // assert
+        assert_eq!(records_back.len(), 2);
+        assert_eq!(parse_row(&records_back[0]), record1);
+        assert_eq!(parse_row(&records_back[1]), record2);
+    }
+
+    #[tokio::test]
+    async fn test_query_headers() {
+        // arrange
+        let (cluster_id, topic_name) = ("cluster_id_example", "topic_name_example");
+        let db = SqliteStore::new(Duration::from_secs(10));
+        db.create_or_replace_topic_table(cluster_id, topic_name, false)
+            .expect("Unable to create the table");
+        let record_without_headers = ParsedKafkaRecord {
+            headers: None,
+            ..get_test_record(topic_name, 1)
+        };
+        // act
+        db.insert_record(cluster_id, topic_name, &get_test_record(topic_name, 0))
+            .unwrap();
+        db.insert_record(cluster_id, topic_name, &record_without_headers)
+            .unwrap();
+        let table_size = db
+            .get_size(&Query {
+                cluster_id: cluster_id.into(),
+                topic_name: topic_name.into(),
+                limit: -1,
+                offset: -1,
+                query_template: "SELECT * from {:topic} WHERE EXISTS (SELECT 1 FROM json_each(headers) \
+                    WHERE json_extract(value, '$[0]') = 'traceId' AND json_extract(value, '$[1]') = 'abc')"
+                    .into(),
+            })
+            .unwrap();
+        // assert
+        assert_eq!(table_size, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reopen_on_disk_store() {
+        // arrange
+        let mut db_path = temp_dir();
+        db_path.push(format!("insulator2-test-{}", rand::random::<usize>()));
+        db_path.push("cluster_id_example.db");
+        let (cluster_id, topic_name) = ("cluster_id_example", "topic_name_example");
+        {
+            let db = SqliteStore::open(&db_path, Some(10), Duration::from_secs(10)).unwrap();
+            db.create_or_replace_topic_table(cluster_id, topic_name, false).unwrap();
+            db.insert_record(cluster_id, topic_name, &get_test_record(topic_name, 0))
+                .unwrap();
+            db.insert_record(cluster_id, topic_name, &get_test_record(topic_name, 1))
+                .unwrap();
+        }
+        // act
+        let db = SqliteStore::open(&db_path, Some(10), Duration::from_secs(10)).unwrap();
+        let records_count = db.open_topic_table(cluster_id, topic_name).unwrap();
+        let stored_topics = db.list_topic_tables(cluster_id).unwrap();
+        let other_cluster_topics = db.list_topic_tables("another_cluster").unwrap();
+        db.destroy(cluster_id, topic_name).unwrap();
+        let stored_topics_after_delete = db.list_topic_tables(cluster_id).unwrap();
+        // assert
+        assert_eq!(records_count, Some(2));
+        assert_eq!(
+            stored_topics,
+            vec![StoredTopic {
+                topic_name: topic_name.into(),
+                records_count: 2
+            }]
+        );
+        assert!(other_cluster_topics.is_empty());
+        assert!(stored_topics_after_delete.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_open_topic_table_does_not_create_the_table() {
+        let (cluster_id, topic_name) = ("cluster_id_example", "topic_name_example");
+        let db = SqliteStore::new(Duration::from_secs(10));
+        assert_eq!(db.open_topic_table(cluster_id, topic_name), Ok(None));
+        assert!(db.list_topic_tables(cluster_id).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_tables_of_previous_versions() {
+        // arrange
+        let mut db_path = temp_dir();
+        db_path.push(format!("insulator2-test-{}", rand::random::<usize>()));
+        db_path.push("cluster_id_example.db");
+        let (cluster_id, topic_name) = ("cluster_id_example", "topic_name_example");
+        {
+            let db = SqliteStore::open(&db_path, None, Duration::from_secs(10)).unwrap();
+            let connection = db.pool.get().unwrap();
+            // table layout before the introduction of the key_schema_id column
+            connection
+                .execute(
+                    "CREATE TABLE '[cluster_id_example].[topic_name_example]' (
+                        payload      TEXT,
+                        key          TEXT,
+                        topic        TEXT NOT NULL,
+                        timestamp    NUMBER,
+                        partition    NUMBER NON NULL,
+                        offset       NUMBER NON NULL,
+                        schema_id    NUMBER,
+                        record_bytes NUMBER,
+                        headers      TEXT,
+                    PRIMARY KEY (topic, partition, offset))",
+                    [],
+                )
+                .unwrap();
+        }
+        // act
+        let db = SqliteStore::open(&db_path, None, Duration::from_secs(10)).unwrap();
+        let res = db.insert_record(cluster_id, topic_name, &get_test_record(topic_name, 0));
+        // assert
+        assert!(res.is_ok());
+        let records = db
+            .query_records(&Query::select_any(cluster_id, topic_name, 0, 10), None)
+            .unwrap();
+        assert_eq!(parse_row(&records[0]), get_test_record(topic_name, 0));
+    }
+
     #[tokio::test]
     async fn test_use_offset() {
         // arrange
@@ -512,12 +784,14 @@ mod tests {
             timestamp: Some(321123321),
             partition: 2,
             offset,
-            schema_id: None,
+            schema_id: Some(1),
+            key_schema_id: Some(2),
             record_bytes: 123,
+            headers: Some(r#"[["traceId","abc"]]"#.into()),
         }
     }
 
-    fn parse_row(row: &QueryResultRow, topic_name: &str) -> ParsedKafkaRecord {
+    fn parse_row(row: &QueryResultRow) -> ParsedKafkaRecord {
         ParsedKafkaRecord {
             payload: match row.get(Query::PAYLOAD) {
                 None => None,
@@ -529,9 +803,10 @@ mod tests {
                 Some(crate::core::record_store::QueryResultRowItem::Text(v)) => Some(v.to_string()),
                 _ => panic!("invalid type"),
             },
-            // the topic name is not part of the table since can be retrieved
-            // by the table name
-            topic: topic_name.into(),
+            topic: match row.get(Query::TOPIC) {
+                Some(crate::core::record_store::QueryResultRowItem::Text(v)) => v.to_string(),
+                _ => panic!("invalid type"),
+            },
             timestamp: match row.get(Query::TIMESTAMP) {
                 None => None,
                 Some(crate::core::record_store::QueryResultRowItem::Integer(v)) => Some((*v).try_into().unwrap()),
@@ -545,8 +820,22 @@ mod tests {
                 Some(crate::core::record_store::QueryResultRowItem::Integer(v)) => *v,
                 _ => panic!("invalid type"),
             },
-            schema_id: None,
+            schema_id: match row.get(Query::SCHEMA_ID) {
+                None | Some(crate::core::record_store::QueryResultRowItem::Null) => None,
+                Some(crate::core::record_store::QueryResultRowItem::Integer(v)) => Some(*v as i32),
+                _ => panic!("invalid type"),
+            },
+            key_schema_id: match row.get(Query::KEY_SCHEMA_ID) {
+                None | Some(crate::core::record_store::QueryResultRowItem::Null) => None,
+                Some(crate::core::record_store::QueryResultRowItem::Integer(v)) => Some(*v as i32),
+                _ => panic!("invalid type"),
+            },
             record_bytes: 123,
+            headers: match row.get(Query::HEADERS) {
+                None | Some(crate::core::record_store::QueryResultRowItem::Null) => None,
+                Some(crate::core::record_store::QueryResultRowItem::Text(v)) => Some(v.to_string()),
+                _ => panic!("invalid type"),
+            },
         }
     }
 }
@@ -1,7 +1,10 @@
 use log::{debug, trace};
 use rdkafka::message::ToBytes;
 
-use crate::core::{parser::Parser, types::RawKafkaRecord};
+use crate::core::{
+    parser::Parser,
+    types::{ParserModes, RawKafkaRecord},
+};
 use std::{
     cmp::Ordering,
     fs::OpenOptions,
@@ -24,26 +27,37 @@ pub struct TopicStore<S: RecordStore = SqliteStore, P: KafkaRecordParser = Parse
     store: Arc<S>,
     parser: Arc<P>,
     records_counter: RwLock<usize>,
+    parser_modes: RwLock<ParserModes>,
+    /// false until the first consumer starts, if no records were stored in previous sessions
+    has_table: RwLock<bool>,
 }
 
 impl<S: RecordStore, P: KafkaRecordParser> TopicStore<S, P> {
+    /// Open the table of the topic, reusing the records stored in a previous session if any.
+    /// The table is recreated by `setup` when the consumer starts
     pub fn from_record_store(store: Arc<S>, parser: Arc<P>, cluster_id: &str, topic_name: &str) -> Self {
-        store
-            .create_or_replace_topic_table(cluster_id, topic_name, false)
-            .unwrap_or_else(|_| panic!("Unable to create the table to store the records from topic {topic_name}"));
+        let records_count = store
+            .open_topic_table(cluster_id, topic_name)
+            .unwrap_or_else(|_| panic!("Unable to open the table storing the records from topic {topic_name}"));
         TopicStore {
             cluster_id: cluster_id.to_string(),
             topic_name: topic_name.to_string(),
             store,
             parser,
-            records_counter: Default::default(),
+            records_counter: RwLock::new(records_count.unwrap_or_default()),
+            parser_modes: Default::default(),
+            has_table: RwLock::new(records_count.is_some()),
         }
     }
 
-    pub fn setup(&self, compactify: bool) -> StoreResult<()> {
+    /// Recreate the table to store the consumed records, dropping the records already stored
+    pub fn setup(&self, compactify: bool, parser_modes: ParserModes) -> StoreResult<()> {
         *self.records_counter.write().unwrap() = 0;
+        *self.parser_modes.write().unwrap() = parser_modes;
         self.store
-            .create_or_replace_topic_table(&self.cluster_id, &self.topic_name, compactify)
+            .create_or_replace_topic_table(&self.cluster_id, &self.topic_name, compactify)?;
+        *self.has_table.write().unwrap() = true;
+        Ok(())
     }
 
     pub fn get_records(
@@ -53,6 +67,9 @@ impl<S: RecordStore, P: KafkaRecordParser> TopicStore<S, P> {
         limit: i64,
         timeout: Option<Duration>,
     ) -> StoreResult<Vec<QueryResultRow>> {
+        if !*self.has_table.read().unwrap() {
+            return Ok(vec![]);
+        }
         self.store.query_records(
             &Query {
                 cluster_id: self.cluster_id.clone(),
@@ -71,7 +88,8 @@ impl<S: RecordStore, P: KafkaRecordParser> TopicStore<S, P> {
 
     pub async fn insert_record(&self, record: &RawKafkaRecord) -> StoreResult<()> {
         *self.records_counter.write().unwrap() += 1;
-        let parsed_record = self.parser.parse_kafka_record(record).await?;
+        let parser_modes = *self.parser_modes.read().unwrap();
+        let parsed_record = self.parser.parse_kafka_record(record, &parser_modes).await?;
         self.store
             .insert_record(&self.cluster_id, &self.topic_name, &parsed_record)
     }
@@ -186,14 +204,14 @@ mod test {
     use crate::core::record_store::topic_store::sort_columns;
     use crate::core::record_store::types::ExportOptions;
     use crate::core::record_store::QueryResultRowItem;
-    use crate::core::types::{ParsedKafkaRecord, RawKafkaRecord};
+    use crate::core::types::{ParsedKafkaRecord, ParserModes, RawKafkaRecord};
     use async_trait::async_trait;
 
     mock! {
         Parser {}
         #[async_trait]
         impl KafkaRecordParser for Parser {
-            async fn parse_kafka_record(&self, record: &RawKafkaRecord) -> StoreResult<ParsedKafkaRecord>;
+            async fn parse_kafka_record(&self, record: &RawKafkaRecord, modes: &ParserModes) -> StoreResult<ParsedKafkaRecord>;
         }
     }
     mock! {
@@ -201,6 +219,7 @@ mod test {
         impl RecordStore for Store {
             fn query_records(&self, query: &Query, timeout: Option<Duration>) -> StoreResult<Vec<QueryResultRow>>;
             fn create_or_replace_topic_table(&self, cluster_id: &str, topic_name: &str, compacted: bool) -> StoreResult<()>;
+            fn open_topic_table(&self, cluster_id: &str, topic_name: &str) -> StoreResult<Option<usize>>;
             fn insert_record(&self, cluster_id: &str, topic_name: &str, record: &ParsedKafkaRecord) -> StoreResult<()>;
             fn destroy(&self, cluster_id: &str, topic_name: &str) -> StoreResult<()>;
         }
@@ -246,8 +265,8 @@ mod test {
         let mut mock_record_store = MockStore::new();
         let parser_mock = MockParser::new();
         mock_record_store
-            .expect_create_or_replace_topic_table()
-            .returning(|_, _, _| Ok(()));
+            .expect_open_topic_table()
+            .returning(|_, _| Ok(Some(0)));
         mock_record_store
             .expect_query_records()
             .returning(|_, _| Ok(vec![create_test_record(0), create_test_record(1)]));
@@ -285,8 +304,8 @@ mod test {
         let parser_mock = MockParser::new();
         mock_record_store.expect_query_records().returning(|_, _| Ok(vec![]));
         mock_record_store
-            .expect_create_or_replace_topic_table()
-            .returning(|_, _, _| Ok(()));
+            .expect_open_topic_table()
+            .returning(|_, _| Ok(Some(0)));
         let sut = TopicStore::from_record_store(
             Arc::new(mock_record_store),
             Arc::new(parser_mock),
@@ -311,12 +330,64 @@ mod test {
     }
 
     #[test]
-    fn test_overwrite_files() {
+    fn test_get_records_before_the_table_is_created() {
+        // arrange
+        let mut mock_record_store = MockStore::new();
+        mock_record_store.expect_open_topic_table().returning(|_, _| Ok(None));
+        mock_record_store
+            .expect_create_or_replace_topic_table()
+            .returning(|_, _, _| Ok(()));
+        mock_record_store
+            .expect_query_records()
+            .times(1)
+            .returning(|_, _| Ok(vec![create_test_record(0)]));
+        let sut = TopicStore::from_record_store(
+            Arc::new(mock_record_store),
+            Arc::new(MockParser::new()),
+            "cluster_id",
+            "topic_name",
+        );
+        // act
+        let before_setup = sut.get_records(None, 0, 10, None).unwrap();
+        sut.setup(false, Default::default()).unwrap();
+        let after_setup = sut.get_records(None, 0, 10, None).unwrap();
+        // assert
+        assert!(before_setup.is_empty());
+        assert_eq!(after_setup.len(), 1);
+    }
+
+    #[test]
+    fn test_setup_drops_the_records_of_previous_sessions() {
+        // arrange
         let mut mock_record_store = MockStore::new();
-        let parser_mock = MockParser::new();
+        mock_record_store
+            .expect_open_topic_table()
+            .returning(|_, _| Ok(Some(5)));
         mock_record_store
             .expect_create_or_replace_topic_table()
+            .times(1)
             .returning(|_, _, _| Ok(()));
+        let sut = TopicStore::from_record_store(
+            Arc::new(mock_record_store),
+            Arc::new(MockParser::new()),
+            "cluster_id",
+            "topic_name",
+        );
+        let reopened_count = sut.get_records_count().unwrap();
+        // act
+        sut.setup(false, Default::default()).unwrap();
+        // assert
+        assert_eq!(reopened_count, 5);
+        assert_eq!(sut.get_records_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_overwrite_files() {
+        let mut mock_record_store = MockStore::new();
+        let parser_mock = MockParser::new();
+        mock_record_store
+            .expect_open_topic_table()
+            .returning(|_, _| Ok(Some(0)));
         mock_record_store.expect_query_records().returning(|_, _| Ok(vec![]));
         let sut = TopicStore::from_record_store(
             Arc::new(mock_record_store),
@@ -10,3 +10,11 @@ pub struct ExportOptions {
     pub parse_timestamp: bool,
     pub overwrite: bool,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StoredTopic {
+    #[serde(rename = "topicName")]
+    pub topic_name: String,
+    #[serde(rename = "recordsCount")]
+    pub records_count: usize,
+}
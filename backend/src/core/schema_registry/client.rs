@@ -1,22 +1,42 @@
 use log::{debug, trace};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use url::Url;
 
 use apache_avro::Schema as AvroSchema;
 
-use crate::core::avro::ResolvedAvroSchema;
+use crate::core::avro::{ResolvedAvroSchema, SchemaChange};
+use crate::core::json_schema::ResolvedJsonSchema;
+use crate::core::protobuf::ResolvedProtoSchema;
 use crate::core::schema_registry::http_client::HttpClientError;
 
 use super::error::{SchemaRegistryError, SchemaRegistryResult};
 use super::http_client::{HttpClient, ReqwestClient};
-use super::types::{BasicAuth, Schema, Subject};
+use super::types::{BasicAuth, CompatibilityCheck, Schema, SchemaReference, SchemaRegistryMode, SchemaType, Subject};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 struct GetSchemaByIdResult {
     pub schema: String,
+    #[serde(rename = "schemaType", default)]
+    pub schema_type: SchemaType,
+    #[serde(default)]
+    pub references: Vec<SchemaReference>,
+}
+
+#[derive(Serialize)]
+struct PostSchemaRequest {
+    schema: String,
+    #[serde(rename = "schemaType")]
+    schema_type: SchemaType,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    references: Vec<SchemaReference>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ModeRequestResponse {
+    mode: SchemaRegistryMode,
 }
 
 #[derive(Clone)]
@@ -24,6 +44,9 @@ pub struct CachedSchemaRegistry<C: HttpClient = ReqwestClient> {
     http_client: C,
     endpoint: String,
     schema_cache_by_id: Arc<RwLock<HashMap<i32, ResolvedAvroSchema>>>,
+    proto_schema_cache_by_id: Arc<RwLock<HashMap<i32, ResolvedProtoSchema>>>,
+    json_schema_cache_by_id: Arc<RwLock<HashMap<i32, ResolvedJsonSchema>>>,
+    raw_schema_cache_by_id: Arc<RwLock<HashMap<i32, GetSchemaByIdResult>>>,
 }
 
 impl CachedSchemaRegistry<ReqwestClient> {
@@ -50,19 +73,26 @@ impl<C: HttpClient> CachedSchemaRegistry<C> {
             http_client,
             endpoint: endpoint.into(),
             schema_cache_by_id: Arc::new(RwLock::new(HashMap::new())),
+            proto_schema_cache_by_id: Arc::new(RwLock::new(HashMap::new())),
+            json_schema_cache_by_id: Arc::new(RwLock::new(HashMap::new())),
+            raw_schema_cache_by_id: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
-    pub async fn post_schema(&self, subject_name: &str, schema: &str) -> SchemaRegistryResult<()> {
-        #[derive(Serialize)]
-        struct PostRequest {
-            schema: String,
-        }
+    pub async fn post_schema(
+        &self,
+        subject_name: &str,
+        schema: &str,
+        schema_type: SchemaType,
+        references: &[SchemaReference],
+    ) -> SchemaRegistryResult<()> {
         let url = Url::parse(&self.endpoint)?.join(format!("/subjects/{subject_name}/versions").as_str())?;
-        let request = PostRequest { schema: schema.into() };
-        if let Err(err) = AvroSchema::parse_str(schema) {
-            return Err(SchemaRegistryError::SchemaParsing(format!("Invalid schema {err}")));
+        let request = PostSchemaRequest {
+            schema: schema.into(),
+            schema_type,
+            references: references.to_vec(),
         };
+        self.validate_schema(schema, schema_type, references).await?;
         let post_result = self.http_client.post(url.as_str(), request).await;
         debug!("Schema posted: {:?}", post_result);
         match post_result {
@@ -80,31 +110,108 @@ impl<C: HttpClient> CachedSchemaRegistry<C> {
         }
     }
 
+    /// Check the schema against a version of the subject, the latest if None, without registering it
+    pub async fn check_compatibility(
+        &self,
+        subject_name: &str,
+        schema: &str,
+        schema_type: SchemaType,
+        references: &[SchemaReference],
+        version: Option<i32>,
+    ) -> SchemaRegistryResult<CompatibilityCheck> {
+        self.validate_schema(schema, schema_type, references).await?;
+        let version = version.map(|v| v.to_string()).unwrap_or_else(|| "latest".into());
+        let url = Url::parse(&self.endpoint)?
+            .join(format!("/compatibility/subjects/{subject_name}/versions/{version}?verbose=true").as_str())?;
+        let request = PostSchemaRequest {
+            schema: schema.into(),
+            schema_type,
+            references: references.to_vec(),
+        };
+        match self.http_client.post_with_response(url.as_str(), request).await {
+            Ok(res) => Ok(res),
+            Err(HttpClientError::Code(404)) => Err(SchemaRegistryError::SchemaNotFound(format!(
+                "Version {version} of subject {subject_name} not found"
+            ))),
+            Err(HttpClientError::Code(422)) => Err(SchemaRegistryError::SchemaParsing(
+                "Invalid schema, rejected by the schema registry".into(),
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     pub async fn list_subjects(&self) -> SchemaRegistryResult<Vec<String>> {
         let url = Url::parse(&self.endpoint)?.join("subjects")?;
         let res = self.http_client.get(url.as_ref()).await?;
         Ok(res)
     }
 
+    /// List the soft deleted subjects, that can be permanently deleted
+    pub async fn list_deleted_subjects(&self) -> SchemaRegistryResult<Vec<String>> {
+        let url = Url::parse(&self.endpoint)?.join("/subjects?deleted=true")?;
+        let all_subjects: Vec<String> = self.http_client.get(url.as_ref()).await?;
+        let active_subjects = self.list_subjects().await?;
+        Ok(all_subjects
+            .into_iter()
+            .filter(|s| !active_subjects.contains(s))
+            .collect())
+    }
+
+    /// List the soft deleted versions of the subject, that can be permanently deleted
+    pub async fn list_deleted_versions(&self, subject_name: &str) -> SchemaRegistryResult<Vec<i32>> {
+        let url = Url::parse(&self.endpoint)?.join(format!("/subjects/{subject_name}/versions").as_str())?;
+        let all_versions: Vec<i32> = self.http_client.get(format!("{url}?deleted=true").as_str()).await?;
+        // the registry returns 404 once all the versions are soft deleted
+        let active_versions: Vec<i32> = match self.http_client.get(url.as_ref()).await {
+            Err(HttpClientError::Code(404)) => vec![],
+            res => res?,
+        };
+        Ok(all_versions
+            .into_iter()
+            .filter(|v| !active_versions.contains(v))
+            .collect())
+    }
+
     pub async fn get_subject(&self, subject_name: &str) -> SchemaRegistryResult<Subject> {
         debug!("Get subject {}", subject_name);
         Ok(Subject {
             subject: subject_name.into(),
             versions: self.get_versions(subject_name).await?,
-            compatibility: self.get_compatibility_level(subject_name).await?,
+            compatibility: self.get_compatibility_level(Some(subject_name)).await?,
         })
     }
 
-    pub async fn delete_subject(&self, subject_name: &str) -> SchemaRegistryResult<()> {
-        debug!("Deleting subject {}", subject_name);
+    /// Soft delete the subject. The permanent delete removes also the schemas
+    pub async fn delete_subject(&self, subject_name: &str, permanent: bool) -> SchemaRegistryResult<()> {
+        debug!("Deleting subject {} (permanent: {})", subject_name, permanent);
         let url = Url::parse(&self.endpoint)?.join(format!("/subjects/{subject_name}").as_str())?;
-        Ok(self.http_client.delete(url.as_str()).await?)
+        self.delete(url, permanent).await
     }
 
-    pub async fn delete_version(&self, subject_name: &str, version: i32) -> SchemaRegistryResult<()> {
-        debug!("Deleting subject {} version {}", subject_name, version);
+    /// Soft delete the version of the subject. The permanent delete removes also the schema
+    pub async fn delete_version(&self, subject_name: &str, version: i32, permanent: bool) -> SchemaRegistryResult<()> {
+        debug!(
+            "Deleting subject {} version {} (permanent: {})",
+            subject_name, version, permanent
+        );
         let url = Url::parse(&self.endpoint)?.join(format!("/subjects/{subject_name}/versions/{version}").as_str())?;
-        Ok(self.http_client.delete(url.as_str()).await?)
+        self.delete(url, permanent).await
+    }
+
+    async fn delete(&self, url: Url, permanent: bool) -> SchemaRegistryResult<()> {
+        let soft_delete_result = self.http_client.delete(url.as_str()).await;
+        if !permanent {
+            return Ok(soft_delete_result?);
+        }
+        // the registry requires a soft delete before the permanent one, the resource may already be soft deleted
+        match soft_delete_result {
+            Ok(_) | Err(HttpClientError::Code(404)) => {}
+            Err(err) => return Err(err.into()),
+        }
+        Ok(self
+            .http_client
+            .delete(format!("{url}?permanent=true").as_str())
+            .await?)
     }
 
     pub async fn get_schema_by_id(&self, id: i32) -> SchemaRegistryResult<ResolvedAvroSchema> {
@@ -117,18 +224,162 @@ impl<C: HttpClient> CachedSchemaRegistry<C> {
         }
         {
             trace!("Schema not found in cache, retrieving");
-            let url = Url::parse(&self.endpoint)?.join(format!("/schemas/ids/{id}").as_str())?;
-            let schema: GetSchemaByIdResult = self.http_client.get(url.as_str()).await?;
-            let schema = AvroSchema::parse_str(schema.schema.as_str()).map_err(|err| {
-                SchemaRegistryError::SchemaParsing(format!("Unable to parse the schema from schema registry\n{err}"))
-            })?;
-            let res = ResolvedAvroSchema::from(id, &schema);
+            let schema = self.get_raw_schema_by_id(id).await?;
+            Self::check_schema_type(id, schema.schema_type, SchemaType::Avro)?;
+            let res = self.resolve_avro_schema(id, &schema.schema, &schema.references).await?;
             self.schema_cache_by_id.write().await.insert(id, res.clone());
             Ok(res)
         }
     }
 
-    pub async fn set_compatibility_level(&self, subject_name: &str, compatibility: &str) -> SchemaRegistryResult<String> {
+    pub async fn get_proto_schema_by_id(&self, id: i32) -> SchemaRegistryResult<ResolvedProtoSchema> {
+        trace!("Getting protobuf schema {} by id.", id);
+        {
+            if let Some(cached) = self.proto_schema_cache_by_id.read().await.get(&id) {
+                trace!("Schema found in cache");
+                return Ok(cached.clone());
+            }
+        }
+        {
+            trace!("Schema not found in cache, retrieving");
+            let schema = self.get_raw_schema_by_id(id).await?;
+            Self::check_schema_type(id, schema.schema_type, SchemaType::Protobuf)?;
+            let res = self
+                .resolve_proto_schema(id, &schema.schema, &schema.references)
+                .await?;
+            self.proto_schema_cache_by_id.write().await.insert(id, res.clone());
+            Ok(res)
+        }
+    }
+
+    pub async fn get_json_schema_by_id(&self, id: i32) -> SchemaRegistryResult<ResolvedJsonSchema> {
+        trace!("Getting json schema {} by id.", id);
+        {
+            if let Some(cached) = self.json_schema_cache_by_id.read().await.get(&id) {
+                trace!("Schema found in cache");
+                return Ok(cached.clone());
+            }
+        }
+        {
+            trace!("Schema not found in cache, retrieving");
+            let schema = self.get_raw_schema_by_id(id).await?;
+            Self::check_schema_type(id, schema.schema_type, SchemaType::Json)?;
+            let res = ResolvedJsonSchema::compile(id, &schema.schema).map_err(|err| {
+                SchemaRegistryError::SchemaParsing(format!("Unable to parse the schema from schema registry\n{err:?}"))
+            })?;
+            self.json_schema_cache_by_id.write().await.insert(id, res.clone());
+            Ok(res)
+        }
+    }
+
+    /// Retrieve the schema with the specified id without parsing it.
+    /// The result is cached to cheaply reject the records with a schema of an unexpected type
+    async fn get_raw_schema_by_id(&self, id: i32) -> SchemaRegistryResult<GetSchemaByIdResult> {
+        {
+            if let Some(cached) = self.raw_schema_cache_by_id.read().await.get(&id) {
+                return Ok(cached.clone());
+            }
+        }
+        let url = Url::parse(&self.endpoint)?.join(format!("/schemas/ids/{id}").as_str())?;
+        let schema: GetSchemaByIdResult = self.http_client.get(url.as_str()).await?;
+        self.raw_schema_cache_by_id.write().await.insert(id, schema.clone());
+        Ok(schema)
+    }
+
+    /// Parse the avro schema together with the schemas it references.
+    /// Every avro schema, retrieved or submitted, is resolved here to handle the references consistently
+    async fn resolve_avro_schema(
+        &self,
+        id: i32,
+        schema: &str,
+        references: &[SchemaReference],
+    ) -> SchemaRegistryResult<ResolvedAvroSchema> {
+        let referenced_schemas = self
+            .get_referenced_schemas(references)
+            .await?
+            .into_iter()
+            .map(|(_, schema)| schema);
+        let (schema, referenced_schemas) = AvroSchema::parse_str_with_list(schema, referenced_schemas)
+            .map_err(|err| SchemaRegistryError::SchemaParsing(format!("Unable to parse the avro schema\n{err}")))?;
+        Ok(ResolvedAvroSchema::from(id, &schema, referenced_schemas))
+    }
+
+    /// Compile the protobuf schema, resolving its imports with the schemas it references
+    async fn resolve_proto_schema(
+        &self,
+        id: i32,
+        schema: &str,
+        references: &[SchemaReference],
+    ) -> SchemaRegistryResult<ResolvedProtoSchema> {
+        let imports = self.get_referenced_schemas(references).await?;
+        ResolvedProtoSchema::compile(id, schema, &imports)
+            .map_err(|err| SchemaRegistryError::SchemaParsing(format!("Unable to parse the protobuf schema\n{err:?}")))
+    }
+
+    /// Retrieve the raw schemas referenced directly or transitively by a schema, with their reference name
+    async fn get_referenced_schemas(
+        &self,
+        references: &[SchemaReference],
+    ) -> SchemaRegistryResult<Vec<(String, String)>> {
+        let mut to_visit = references.to_vec();
+        let mut visited = HashSet::new();
+        let mut schemas = Vec::new();
+        while let Some(SchemaReference { name, subject, version }) = to_visit.pop() {
+            if !visited.insert((subject.clone(), version)) {
+                continue;
+            }
+            trace!("Retrieving the referenced schema {} version {}", subject, version);
+            let schema = self.get_version(&subject, version).await?;
+            to_visit.extend(schema.references);
+            schemas.push((name, schema.schema));
+        }
+        Ok(schemas)
+    }
+
+    async fn get_version(&self, subject_name: &str, version: i32) -> SchemaRegistryResult<Schema> {
+        let url = Url::parse(&self.endpoint)?.join(format!("/subjects/{subject_name}/versions/{version}").as_str())?;
+        self.http_client.get(url.as_str()).await.map_err(|err| match err {
+            HttpClientError::Code(404) => {
+                SchemaRegistryError::SchemaNotFound(format!("Schema {subject_name} version {version} not found"))
+            }
+            _ => err.into(),
+        })
+    }
+
+    async fn validate_schema(
+        &self,
+        schema: &str,
+        schema_type: SchemaType,
+        references: &[SchemaReference],
+    ) -> SchemaRegistryResult<()> {
+        let validation_result = match schema_type {
+            SchemaType::Avro => return self.resolve_avro_schema(0, schema, references).await.map(|_| ()),
+            SchemaType::Protobuf => return self.resolve_proto_schema(0, schema, references).await.map(|_| ()),
+            // the json schema references can't be resolved locally, the schema is validated by the registry
+            SchemaType::Json if !references.is_empty() => Ok(()),
+            SchemaType::Json => ResolvedJsonSchema::compile(0, schema)
+                .map(|_| ())
+                .map_err(|err| format!("{err:?}")),
+        };
+        validation_result.map_err(|err| SchemaRegistryError::SchemaParsing(format!("Invalid schema {err}")))
+    }
+
+    fn check_schema_type(id: i32, actual: SchemaType, expected: SchemaType) -> SchemaRegistryResult<()> {
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(SchemaRegistryError::SchemaParsing(format!(
+                "The schema {id} is {actual:?}, expected {expected:?}"
+            )))
+        }
+    }
+
+    /// Set the compatibility level of the subject, the global one if None
+    pub async fn set_compatibility_level(
+        &self,
+        subject_name: Option<&str>,
+        compatibility: &str,
+    ) -> SchemaRegistryResult<String> {
         #[derive(Deserialize, Serialize)]
         struct CompatibilityRequestResponse {
             compatibility: String,
@@ -146,7 +397,7 @@ impl<C: HttpClient> CachedSchemaRegistry<C> {
             let request = CompatibilityRequestResponse {
                 compatibility: compatibility.to_string(),
             };
-            let url = Url::parse(&self.endpoint)?.join(format!("/config/{subject_name}").as_str())?;
+            let url = Url::parse(&self.endpoint)?.join(&Self::subject_path("/config", subject_name))?;
             let response: CompatibilityRequestResponse = self.http_client.put(url.as_ref(), request).await?;
             Ok(response.compatibility)
         } else {
@@ -156,17 +407,48 @@ impl<C: HttpClient> CachedSchemaRegistry<C> {
         }
     }
 
-    async fn get_compatibility_level(&self, subject_name: &str) -> SchemaRegistryResult<String> {
+    /// Retrieve the compatibility level of the subject, the global one if None.
+    /// The subjects without a compatibility level use the global one
+    pub async fn get_compatibility_level(&self, subject_name: Option<&str>) -> SchemaRegistryResult<String> {
         #[derive(Deserialize)]
         struct CompatibilityResponse {
             #[serde(alias = "compatibilityLevel")]
             compatibility_level: String,
         }
-        let url = Url::parse(&self.endpoint)?.join(format!("/config/{subject_name}?defaultToGlobal=true").as_str())?;
+        let path = Self::subject_path("/config", subject_name);
+        let url = Url::parse(&self.endpoint)?.join(format!("{path}?defaultToGlobal=true").as_str())?;
         let response: CompatibilityResponse = self.http_client.get(url.as_ref()).await?;
         Ok(response.compatibility_level)
     }
 
+    /// Retrieve the mode of the subject, the global one if None.
+    /// The subjects without a mode use the global one
+    pub async fn get_mode(&self, subject_name: Option<&str>) -> SchemaRegistryResult<SchemaRegistryMode> {
+        let path = Self::subject_path("/mode", subject_name);
+        let url = Url::parse(&self.endpoint)?.join(format!("{path}?defaultToGlobal=true").as_str())?;
+        let response: ModeRequestResponse = self.http_client.get(url.as_ref()).await?;
+        Ok(response.mode)
+    }
+
+    /// Set the mode of the subject, the global one if None
+    pub async fn set_mode(
+        &self,
+        subject_name: Option<&str>,
+        mode: SchemaRegistryMode,
+    ) -> SchemaRegistryResult<SchemaRegistryMode> {
+        debug!("Set mode {:?} for subject {:?}", mode, subject_name);
+        let url = Url::parse(&self.endpoint)?.join(&Self::subject_path("/mode", subject_name))?;
+        let response: ModeRequestResponse = self.http_client.put(url.as_ref(), ModeRequestResponse { mode }).await?;
+        Ok(response.mode)
+    }
+
+    fn subject_path(resource: &str, subject_name: Option<&str>) -> String {
+        match subject_name {
+            Some(subject_name) => format!("{resource}/{subject_name}"),
+            None => resource.into(),
+        }
+    }
+
     async fn get_versions(&self, subject_name: &str) -> SchemaRegistryResult<Vec<Schema>> {
         let url = Url::parse(&self.endpoint)?.join(format!("/subjects/{subject_name}/versions/").as_str())?;
         let versions: Vec<i32> = self.http_client.get(url.as_ref()).await?;
@@ -179,19 +461,460 @@ impl<C: HttpClient> CachedSchemaRegistry<C> {
         Ok(schemas)
     }
 
+    /// Structural changes from a version of the subject to another. Only avro schemas are supported
+    pub async fn diff_versions(
+        &self,
+        subject_name: &str,
+        from_version: i32,
+        to_version: i32,
+    ) -> SchemaRegistryResult<Vec<SchemaChange>> {
+        debug!(
+            "Diff subject {} versions {} and {}",
+            subject_name, from_version, to_version
+        );
+        let mut resolved = Vec::with_capacity(2);
+        for version in [from_version, to_version] {
+            let schema = self.get_version(subject_name, version).await?;
+            Self::check_schema_type(schema.id, schema.schema_type, SchemaType::Avro)?;
+            resolved.push(
+                self.resolve_avro_schema(schema.id, &schema.schema, &schema.references)
+                    .await?,
+            );
+        }
+        Ok(resolved[0].schema.diff(&resolved[1].schema))
+    }
+
     pub async fn get_last_schema(&self, subject_name: &str) -> SchemaRegistryResult<ResolvedAvroSchema> {
         let schemas = self.get_versions(subject_name).await?;
         let last = schemas.iter().max_by(|x, y| x.version.cmp(&y.version));
 
         if let Some(last) = last {
-            let schema = AvroSchema::parse_str(last.schema.as_str()).map_err(|err| {
-                SchemaRegistryError::SchemaParsing(format!("Unable to parse the schema from schema registry\n{err}"))
-            })?;
-            Ok(ResolvedAvroSchema::from(last.id, &schema))
+            Self::check_schema_type(last.id, last.schema_type, SchemaType::Avro)?;
+            self.resolve_avro_schema(last.id, &last.schema, &last.references).await
         } else {
             Err(SchemaRegistryError::SchemaNotFound(format!(
                 "Schema {subject_name} not found"
             )))
         }
     }
+
+    pub async fn get_last_proto_schema(&self, subject_name: &str) -> SchemaRegistryResult<ResolvedProtoSchema> {
+        let schemas = self.get_versions(subject_name).await?;
+        let last = schemas.iter().max_by(|x, y| x.version.cmp(&y.version));
+
+        if let Some(last) = last {
+            Self::check_schema_type(last.id, last.schema_type, SchemaType::Protobuf)?;
+            self.resolve_proto_schema(last.id, &last.schema, &last.references).await
+        } else {
+            Err(SchemaRegistryError::SchemaNotFound(format!(
+                "Schema {subject_name} not found"
+            )))
+        }
+    }
+
+    pub async fn get_last_json_schema(&self, subject_name: &str) -> SchemaRegistryResult<ResolvedJsonSchema> {
+        let schemas = self.get_versions(subject_name).await?;
+        let last = schemas.iter().max_by(|x, y| x.version.cmp(&y.version));
+
+        if let Some(last) = last {
+            Self::check_schema_type(last.id, last.schema_type, SchemaType::Json)?;
+            ResolvedJsonSchema::compile(last.id, &last.schema).map_err(|err| {
+                SchemaRegistryError::SchemaParsing(format!("Unable to parse the schema from schema registry\n{err:?}"))
+            })
+        } else {
+            Err(SchemaRegistryError::SchemaNotFound(format!(
+                "Schema {subject_name} not found"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::{
+        prelude::HttpMockRequest,
+        Method::{DELETE, GET, POST, PUT},
+        MockServer,
+    };
+    use serde_json::json;
+
+    use crate::core::schema_registry::{
+        CompatibilityCheck, SchemaReference, SchemaRegistryError, SchemaRegistryMode, SchemaType,
+    };
+
+    use super::CachedSchemaRegistry;
+
+    const SCHEMA: &str = r#"{"type": "record", "name": "Test", "fields": [{"name": "a", "type": "int"}]}"#;
+    const ADDRESS_SCHEMA: &str = r#"{"type": "record", "name": "Address", "namespace": "com.example", "fields": [{"name": "street", "type": "string"}]}"#;
+    const USER_SCHEMA: &str = r#"{"type": "record", "name": "User", "namespace": "com.example", "fields": [{"name": "address", "type": "com.example.Address"}]}"#;
+
+    fn without_query(req: &HttpMockRequest) -> bool {
+        req.query_params.iter().flatten().next().is_none()
+    }
+
+    fn address_reference() -> SchemaReference {
+        SchemaReference {
+            name: "com.example.Address".into(),
+            subject: "address".into(),
+            version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_schema_by_id_with_references() {
+        let server = MockServer::start();
+        let schema_mock = server.mock(|when, then| {
+            when.method(GET).path("/schemas/ids/10");
+            then.status(200)
+                .json_body(json!({"schema": USER_SCHEMA, "references": [address_reference()]}));
+        });
+        let reference_mock = server.mock(|when, then| {
+            when.method(GET).path("/subjects/address/versions/1");
+            then.status(200).json_body(
+                json!({"subject": "address", "id": 9, "version": 1, "schema": ADDRESS_SCHEMA, "references": []}),
+            );
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        let res = sut.get_schema_by_id(10).await.expect("Unable to resolve the schema");
+        assert_eq!(res.id, 10);
+        assert_eq!(res.referenced_schemas.len(), 1);
+        schema_mock.assert();
+        reference_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_diff_versions() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/test/versions/1");
+            then.status(200)
+                .json_body(json!({"id": 1, "version": 1, "schema": SCHEMA}));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/test/versions/2");
+            then.status(200).json_body(json!({"id": 2, "version": 2, "schema":
+                r#"{"type": "record", "name": "Test", "fields": [{"name": "a", "type": "long"}]}"#}));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        let res = sut.diff_versions("test", 1, 2).await.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].path, "Test.a");
+        assert!(res[0].backward_compatible && !res[0].forward_compatible);
+        // unknown version
+        let res = sut.diff_versions("test", 1, 3).await;
+        assert!(matches!(res, Err(SchemaRegistryError::SchemaNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_diff_versions_with_references() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/address/versions/1");
+            then.status(200)
+                .json_body(json!({"id": 9, "version": 1, "schema": ADDRESS_SCHEMA}));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/user/versions/1");
+            then.status(200)
+                .json_body(json!({"id": 10, "version": 1, "schema": USER_SCHEMA, "references": [address_reference()]}));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/user/versions/2");
+            then.status(200).json_body(json!({"id": 11, "version": 2, "schema":
+                r#"{"type": "record", "name": "User", "namespace": "com.example", "fields": [{"name": "address", "type": "com.example.Address"}, {"name": "age", "type": "int"}]}"#,
+                "references": [address_reference()]}));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        let res = sut.diff_versions("user", 1, 2).await.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].path, "com.example.User.age");
+    }
+
+    #[tokio::test]
+    async fn test_get_proto_schema_with_references() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/schemas/ids/10");
+            then.status(200).json_body(json!({
+                "schemaType": "PROTOBUF",
+                "schema": "syntax = \"proto3\"; package test; import \"common/address.proto\"; message User { common.Address address = 1; }",
+                "references": [{"name": "common/address.proto", "subject": "address", "version": 1}]
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/address/versions/1");
+            then.status(200)
+                .json_body(json!({"id": 9, "version": 1, "schemaType": "PROTOBUF",
+                "schema": "syntax = \"proto3\"; package common; message Address { string street = 1; }"}));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        let res = sut
+            .get_proto_schema_by_id(10)
+            .await
+            .expect("Unable to resolve the schema");
+        assert_eq!(res.get_message(&[0]).unwrap().full_name(), "test.User");
+    }
+
+    #[tokio::test]
+    async fn test_post_schema_with_references() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/subjects/address/versions/1");
+            then.status(200)
+                .json_body(json!({"id": 9, "version": 1, "schema": ADDRESS_SCHEMA}));
+        });
+        let post_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/subjects/user/versions")
+                .json_body(json!({"schema": USER_SCHEMA, "schemaType": "AVRO", "references": [address_reference()]}));
+            then.status(200).json_body(json!({"id": 10}));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        // the referenced types are unknown without the references
+        let res = sut.post_schema("user", USER_SCHEMA, SchemaType::Avro, &[]).await;
+        assert!(matches!(res, Err(SchemaRegistryError::SchemaParsing(_))));
+        let res = sut
+            .post_schema("user", USER_SCHEMA, SchemaType::Avro, &[address_reference()])
+            .await;
+        assert_eq!(res, Ok(()));
+        post_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_check_compatibility() {
+        let server = MockServer::start();
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        // return the incompatibility messages
+        {
+            let server_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/compatibility/subjects/subject/versions/latest")
+                    .query_param("verbose", "true");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(r#"{"is_compatible": false, "messages": ["READER_FIELD_MISSING_DEFAULT_VALUE"]}"#);
+            });
+            let res = sut
+                .check_compatibility("subject", SCHEMA, SchemaType::Avro, &[], None)
+                .await;
+            assert_eq!(
+                res,
+                Ok(CompatibilityCheck {
+                    is_compatible: false,
+                    messages: vec!["READER_FIELD_MISSING_DEFAULT_VALUE".into()]
+                })
+            );
+            server_mock.assert();
+        }
+        // the messages are omitted when the schema is compatible
+        {
+            let server_mock = server.mock(|when, then| {
+                when.method(POST).path("/compatibility/subjects/subject/versions/2");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(r#"{"is_compatible": true}"#);
+            });
+            let res = sut
+                .check_compatibility("subject", SCHEMA, SchemaType::Avro, &[], Some(2))
+                .await;
+            assert_eq!(
+                res,
+                Ok(CompatibilityCheck {
+                    is_compatible: true,
+                    messages: vec![]
+                })
+            );
+            server_mock.assert();
+        }
+        // the subject doesn't exist
+        {
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/compatibility/subjects/missing/versions/latest");
+                then.status(404);
+            });
+            let res = sut
+                .check_compatibility("missing", SCHEMA, SchemaType::Avro, &[], None)
+                .await;
+            assert!(matches!(res, Err(SchemaRegistryError::SchemaNotFound(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_compatibility_with_references() {
+        let server = MockServer::start();
+        let reference_mock = server.mock(|when, then| {
+            when.method(GET).path("/subjects/address/versions/1");
+            then.status(200)
+                .json_body(json!({"id": 9, "version": 1, "schema": ADDRESS_SCHEMA}));
+        });
+        let check_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/compatibility/subjects/user/versions/latest")
+                .json_body(json!({"schema": USER_SCHEMA, "schemaType": "AVRO", "references": [address_reference()]}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"is_compatible": true}"#);
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        // the referenced types are unknown without the references
+        let res = sut
+            .check_compatibility("user", USER_SCHEMA, SchemaType::Avro, &[], None)
+            .await;
+        assert!(matches!(res, Err(SchemaRegistryError::SchemaParsing(_))));
+        let res = sut
+            .check_compatibility("user", USER_SCHEMA, SchemaType::Avro, &[address_reference()], None)
+            .await;
+        assert_eq!(
+            res,
+            Ok(CompatibilityCheck {
+                is_compatible: true,
+                messages: vec![]
+            })
+        );
+        reference_mock.assert();
+        check_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_check_compatibility_of_an_invalid_schema() {
+        let server = MockServer::start();
+        let server_mock = server.mock(|when, then| {
+            when.method(POST);
+            then.status(200);
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        let res = sut
+            .check_compatibility("subject", "{\"type\": \"unknown\"}", SchemaType::Avro, &[], None)
+            .await;
+        assert!(matches!(res, Err(SchemaRegistryError::SchemaParsing(_))));
+        server_mock.assert_hits(0);
+    }
+
+    #[tokio::test]
+    async fn test_global_compatibility_level() {
+        let server = MockServer::start();
+        let get_mock = server.mock(|when, then| {
+            when.method(GET).path("/config").query_param("defaultToGlobal", "true");
+            then.status(200).json_body(json!({"compatibilityLevel": "BACKWARD"}));
+        });
+        let put_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/config")
+                .json_body(json!({"compatibility": "FULL"}));
+            then.status(200).json_body(json!({"compatibility": "FULL"}));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        assert_eq!(sut.get_compatibility_level(None).await.unwrap(), "BACKWARD");
+        assert_eq!(sut.set_compatibility_level(None, "FULL").await.unwrap(), "FULL");
+        assert!(sut.set_compatibility_level(None, "NOT_VALID").await.is_err());
+        get_mock.assert();
+        put_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_mode() {
+        let server = MockServer::start();
+        let get_global_mock = server.mock(|when, then| {
+            when.method(GET).path("/mode");
+            then.status(200).json_body(json!({"mode": "READWRITE"}));
+        });
+        let get_subject_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/mode/subject")
+                .query_param("defaultToGlobal", "true");
+            then.status(200).json_body(json!({"mode": "READONLY"}));
+        });
+        let put_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/mode/subject")
+                .json_body(json!({"mode": "IMPORT"}));
+            then.status(200).json_body(json!({"mode": "IMPORT"}));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        assert_eq!(sut.get_mode(None).await.unwrap(), SchemaRegistryMode::ReadWrite);
+        assert_eq!(
+            sut.get_mode(Some("subject")).await.unwrap(),
+            SchemaRegistryMode::ReadOnly
+        );
+        assert_eq!(
+            sut.set_mode(Some("subject"), SchemaRegistryMode::Import).await.unwrap(),
+            SchemaRegistryMode::Import
+        );
+        get_global_mock.assert();
+        get_subject_mock.assert();
+        put_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_list_deleted_subjects_and_versions() {
+        let server = MockServer::start();
+        let all_subjects_mock = server.mock(|when, then| {
+            when.method(GET).path("/subjects").query_param("deleted", "true");
+            then.status(200).json_body(json!(["active", "deleted"]));
+        });
+        let active_subjects_mock = server.mock(|when, then| {
+            when.method(GET).path("/subjects").matches(without_query);
+            then.status(200).json_body(json!(["active"]));
+        });
+        let all_versions_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/subjects/deleted/versions")
+                .query_param("deleted", "true");
+            then.status(200).json_body(json!([1, 2]));
+        });
+        let active_versions_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/subjects/deleted/versions")
+                .matches(without_query);
+            then.status(404);
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        assert_eq!(sut.list_deleted_subjects().await.unwrap(), vec!["deleted".to_string()]);
+        assert_eq!(sut.list_deleted_versions("deleted").await.unwrap(), vec![1, 2]);
+        all_subjects_mock.assert();
+        active_subjects_mock.assert();
+        all_versions_mock.assert();
+        active_versions_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_permanent_delete_of_a_soft_deleted_version() {
+        let server = MockServer::start();
+        let soft_delete_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/subjects/subject/versions/1")
+                .matches(without_query);
+            then.status(404);
+        });
+        let permanent_delete_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/subjects/subject/versions/1")
+                .query_param("permanent", "true");
+            then.status(200).json_body(json!(1));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        assert!(sut.delete_version("subject", 1, true).await.is_ok());
+        soft_delete_mock.assert();
+        permanent_delete_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_subject() {
+        let server = MockServer::start();
+        let soft_delete_mock = server.mock(|when, then| {
+            when.method(DELETE).path("/subjects/subject").matches(without_query);
+            then.status(200).json_body(json!([1]));
+        });
+        let permanent_delete_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/subjects/subject")
+                .query_param("permanent", "true");
+            then.status(200).json_body(json!([1]));
+        });
+        let sut = CachedSchemaRegistry::new(&server.base_url(), None, None);
+        assert!(sut.delete_subject("subject", false).await.is_ok());
+        soft_delete_mock.assert();
+        permanent_delete_mock.assert_hits(0);
+    }
 }
@@ -15,14 +15,21 @@ pub enum HttpClientError {
     Code(u16),
 }
 
-pub(super) type Result<T> = std::result::Result<T, HttpClientError>;
+pub(crate) type Result<T> = std::result::Result<T, HttpClientError>;
 
 #[async_trait]
 pub trait HttpClient: Sync + Send {
     async fn get<T: 'static + DeserializeOwned>(&self, url: &str) -> Result<T>;
     async fn delete(&self, url: &str) -> Result<()>;
     async fn post<T: Serialize + Send + Sync>(&self, url: &str, data: T) -> Result<()>;
+    async fn post_with_response<T: Serialize + Send + Sync, R: 'static + DeserializeOwned>(
+        &self,
+        url: &str,
+        data: T,
+    ) -> Result<R>;
     async fn put<T: Serialize + Send + Sync, R: 'static + DeserializeOwned>(&self, url: &str, data: T) -> Result<R>;
+    /// PUT request without a body. The response body, if any, is ignored
+    async fn put_without_body(&self, url: &str) -> Result<()>;
 }
 
 pub struct ReqwestClient {
@@ -49,7 +56,33 @@ impl HttpClient for ReqwestClient {
         } else {
             let error_code = response.status().as_u16();
             let text = response.text().await.unwrap();
-            warn!("Unable to create a schema {:?}", text);
+            warn!("Request to {} failed {:?}", url, text);
+            Err(HttpClientError::Code(error_code))
+        }
+    }
+
+    async fn post_with_response<T: Serialize + Send + Sync, R: 'static + DeserializeOwned>(
+        &self,
+        url: &str,
+        data: T,
+    ) -> Result<R> {
+        let request = self
+            .client
+            .post(url.to_string())
+            .body(serde_json::to_string(&data).unwrap())
+            .headers({
+                let mut h = HeaderMap::new();
+                h.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+                h
+            });
+        let response = self.send_request(request).await?;
+        if response.status().is_success() {
+            let res = response.json().await?;
+            Ok(res)
+        } else {
+            let error_code = response.status().as_u16();
+            let text = response.text().await.unwrap();
+            warn!("Request to {} failed {:?}", url, text);
             Err(HttpClientError::Code(error_code))
         }
     }
@@ -71,7 +104,20 @@ impl HttpClient for ReqwestClient {
         } else {
             let error_code = response.status().as_u16();
             let text = response.text().await.unwrap();
-            warn!("Unable to create a schema {:?}", text);
+            warn!("Request to {} failed {:?}", url, text);
+            Err(HttpClientError::Code(error_code))
+        }
+    }
+
+    async fn put_without_body(&self, url: &str) -> Result<()> {
+        let request = self.client.put(url.to_string());
+        let response = self.send_request(request).await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let error_code = response.status().as_u16();
+            let text = response.text().await.unwrap();
+            warn!("Request to {} failed {:?}", url, text);
             Err(HttpClientError::Code(error_code))
         }
     }
@@ -131,7 +177,7 @@ impl From<reqwest::Error> for HttpClientError {
 #[cfg(test)]
 mod tests {
     use httpmock::{
-        Method::{GET, POST},
+        Method::{GET, POST, PUT},
         MockServer,
     };
     use serde::Deserialize;
@@ -166,6 +212,68 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_post_with_response_request() {
+        let server = MockServer::start();
+        let sut = ReqwestClient::new(None);
+        #[derive(Deserialize, Debug)]
+        struct MockResponse {
+            id: i32,
+        }
+        // return the response body when the request is successful
+        {
+            let server_mock = server.mock(|when, then| {
+                when.method(POST).path("/happy_path").body("{\"a\":1}");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body("{\"id\":1}");
+            });
+            let res = sut
+                .post_with_response::<_, MockResponse>(server.url("/happy_path").as_str(), serde_json::json!({"a": 1}))
+                .await;
+            assert_eq!(res.unwrap().id, 1);
+            server_mock.assert();
+        }
+        // return the error code if any
+        {
+            let server_mock = server.mock(|when, then| {
+                when.method(POST).path("/unprocessable");
+                then.status(422);
+            });
+            let res = sut
+                .post_with_response::<_, MockResponse>(server.url("/unprocessable").as_str(), serde_json::json!({}))
+                .await;
+            assert_eq!(res.err().unwrap(), HttpClientError::Code(422));
+            server_mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_put_without_body_request() {
+        let server = MockServer::start();
+        let sut = ReqwestClient::new(None);
+        // return Ok when the request is successful, even without a response body
+        {
+            let server_mock = server.mock(|when, then| {
+                when.method(PUT).path("/happy_path");
+                then.status(202);
+            });
+            let res = sut.put_without_body(server.url("/happy_path").as_str()).await;
+            assert!(res.is_ok(), "Received {:?}", res);
+            server_mock.assert();
+        }
+        // return the error code if any
+        {
+            let server_mock = server.mock(|when, then| {
+                when.method(PUT).path("/conflict");
+                then.status(409);
+            });
+            let res = sut.put_without_body(server.url("/conflict").as_str()).await;
+            assert_eq!(res.err().unwrap(), HttpClientError::Code(409));
+            server_mock.assert();
+        }
+    }
+
     #[tokio::test]
     async fn test_get_request() {
         let server = MockServer::start();
@@ -5,4 +5,5 @@ mod types;
 
 pub use client::CachedSchemaRegistry;
 pub use error::SchemaRegistryError;
-pub use types::{BasicAuth, Subject};
+pub(crate) use http_client::{HttpClient, HttpClientError, ReqwestClient};
+pub use types::{BasicAuth, CompatibilityCheck, SchemaReference, SchemaRegistryMode, SchemaType, Subject};
@@ -12,6 +12,49 @@ pub struct Schema {
     pub id: i32,
     pub version: i32,
     pub schema: String,
+    #[serde(rename = "schemaType", default)]
+    pub schema_type: SchemaType,
+    #[serde(default)]
+    pub references: Vec<SchemaReference>,
+}
+
+/// Reference to a type defined in another subject
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct SchemaReference {
+    /// Fully qualified name of the referenced type for avro, the import path for protobuf
+    pub name: String,
+    pub subject: String,
+    pub version: i32,
+}
+
+/// Result of the compatibility check of a schema against a version of the subject
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct CompatibilityCheck {
+    #[serde(rename = "isCompatible", alias = "is_compatible")]
+    pub is_compatible: bool,
+    /// Reasons of the incompatibility, empty if the schema is compatible
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
+
+/// Operations allowed on the registry or on a subject
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SchemaRegistryMode {
+    ReadWrite,
+    ReadOnly,
+    /// Register schemas with explicit ids and versions, e.g. to migrate from another registry
+    Import,
+}
+
+/// Format of the schema. The schema registry omits the type for avro schemas
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SchemaType {
+    #[default]
+    Avro,
+    Protobuf,
+    Json,
 }
 
 #[derive(Clone)]
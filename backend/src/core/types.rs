@@ -15,10 +15,10 @@ pub struct RawKafkaRecord {
      * Raw record size in bytes
      */
     pub record_bytes: usize,
-    /*
-     * todo: add
-     * - header
+    /**
+     * Record headers as (key, value) pairs in the same order as in the record
      */
+    pub headers: Vec<(String, Option<Vec<u8>>)>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -33,18 +33,46 @@ pub struct ParsedKafkaRecord {
     pub partition: i32,
     pub offset: i64,
     pub schema_id: Option<i32>,
+    pub key_schema_id: Option<i32>,
     /**
      * Raw record size in bytes
      */
     pub record_bytes: usize,
-    /*
-     * todo: add
-     * - header
+    /**
+     * Record headers serialized as a JSON array of [key, value] pairs, in the record order.
+     * None if the record has no headers
      */
+    pub headers: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 pub enum ParserMode {
     String,
     Avro,
+    Protobuf,
+    /// Confluent json schema wire format
+    Json,
+}
+
+/// Parser mode of key and value. The format is detected automatically if the mode is not set
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParserModes {
+    pub key: Option<ParserMode>,
+    pub value: Option<ParserMode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordPart {
+    Key,
+    Value,
+}
+
+impl RecordPart {
+    /// Schema registry subject of the record part, following the topic name strategy
+    pub fn subject_name(&self, topic_name: &str) -> String {
+        match self {
+            RecordPart::Key => format!("{topic_name}-key"),
+            RecordPart::Value => format!("{topic_name}-value"),
+        }
+    }
 }
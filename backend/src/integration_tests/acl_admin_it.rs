@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::{
+    core::admin::{
+        AclBinding, AclBindingFilter, AclOperation, AclPatternType, AclPermissionType, AclResourceType, KafkaAdmin,
+    },
+    integration_tests::KafkaTest,
+};
+
+#[tokio::test]
+async fn test_acl_admin_client() {
+    // arrange
+    let test_fixture = KafkaTest::default();
+    let cluster_config = test_fixture.build_cluster_config();
+    let sut = KafkaAdmin::new(&cluster_config, test_fixture.tmo).expect("Unable to create the admin client");
+    let principal = format!("User:{}", KafkaTest::get_random_name());
+    let acls = vec![
+        AclBinding {
+            resource_type: AclResourceType::Topic,
+            resource_name: KafkaTest::get_random_name(),
+            pattern_type: AclPatternType::Literal,
+            principal: principal.clone(),
+            host: "*".into(),
+            operation: AclOperation::Read,
+            permission_type: AclPermissionType::Allow,
+        },
+        AclBinding {
+            resource_type: AclResourceType::Group,
+            resource_name: "test-".into(),
+            pattern_type: AclPatternType::Prefixed,
+            principal: principal.clone(),
+            host: "*".into(),
+            operation: AclOperation::All,
+            permission_type: AclPermissionType::Deny,
+        },
+    ];
+    let principal_filter = AclBindingFilter {
+        principal: Some(principal.clone()),
+        ..Default::default()
+    };
+
+    // test create and describe ACLs
+    {
+        // act
+        sut.create_acls(&acls).await.expect("Unable to create the ACLs");
+        let res = sut
+            .describe_acls(&principal_filter)
+            .await
+            .expect("Unable to describe the ACLs");
+        let topic_res = sut
+            .describe_acls(&AclBindingFilter {
+                resource_type: AclResourceType::Topic,
+                ..principal_filter.clone()
+            })
+            .await
+            .expect("Unable to describe the ACLs");
+        // assert
+        assert_eq!(res.len(), 2);
+        assert!(acls.iter().all(|acl| res.contains(acl)));
+        assert_eq!(topic_res, vec![acls[0].clone()]);
+    }
+
+    // test delete ACLs
+    {
+        // act
+        let deleted = sut
+            .delete_acls(&[AclBindingFilter {
+                resource_type: AclResourceType::Group,
+                ..principal_filter.clone()
+            }])
+            .await
+            .expect("Unable to delete the ACLs");
+        let res = sut
+            .describe_acls(&principal_filter)
+            .await
+            .expect("Unable to describe the ACLs");
+        // assert
+        assert_eq!(deleted, vec![acls[1].clone()]);
+        assert_eq!(res, vec![acls[0].clone()]);
+    }
+}
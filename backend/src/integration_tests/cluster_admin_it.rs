@@ -0,0 +1,47 @@
+#![cfg(test)]
+use rdkafka::consumer::{Consumer, StreamConsumer};
+
+use crate::{core::admin::KafkaAdmin, integration_tests::KafkaTest};
+
+#[tokio::test]
+async fn test_cluster_admin_client() {
+    // arrange
+    let test_fixture = KafkaTest::default();
+    let consumer: StreamConsumer = test_fixture.build_kafka_client();
+    let cluster_config = test_fixture.build_cluster_config();
+
+    let sut = KafkaAdmin::new(&cluster_config, test_fixture.tmo).expect("Unable to create the admin client");
+
+    // test describe cluster
+    {
+        // act
+        let res = sut.describe_cluster().await.expect("Unable to describe the cluster");
+        // assert
+        let metadata = consumer
+            .fetch_metadata(None, test_fixture.tmo)
+            .expect("Unable to retrieve the metadata");
+        assert_eq!(res.brokers.len(), metadata.brokers().len());
+        assert_eq!(res.topics_count, metadata.topics().len());
+        assert!(res.controller_id.is_some());
+        assert_eq!(res.brokers.iter().filter(|b| b.is_controller).count(), 1);
+        assert_eq!(res.offline_partitions, 0);
+    }
+
+    // test get broker configuration
+    {
+        // arrange
+        let broker_id = sut
+            .describe_cluster()
+            .await
+            .expect("Unable to describe the cluster")
+            .brokers[0]
+            .id;
+        // act
+        let res = sut
+            .get_broker_configuration(broker_id)
+            .await
+            .expect("Unable to retrieve the broker configuration");
+        // assert
+        assert!(res.contains_key("log.retention.hours"));
+    }
+}
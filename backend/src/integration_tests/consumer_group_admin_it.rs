@@ -1,11 +1,16 @@
 #![cfg(test)]
+use std::{collections::HashMap, env::temp_dir, fs};
+
 use futures::StreamExt;
 use rdkafka::{
     consumer::{Consumer, StreamConsumer},
     producer::{FutureProducer, FutureRecord},
 };
 
-use crate::{core::admin::KafkaAdmin, integration_tests::KafkaTest};
+use crate::{
+    core::admin::{AdminError, KafkaAdmin, MemberAssignment, OffsetReset, OffsetResetStrategy, OffsetsFileFormat},
+    integration_tests::KafkaTest,
+};
 
 #[tokio::test]
 async fn test_consumer_groups_admin_client() {
@@ -105,4 +110,258 @@ async fn test_consumer_groups_admin_client() {
         assert_eq!(res.offsets[0].offset, 0);
         assert_eq!(res.offsets[0].topic, test_topic_name);
     }
+
+    // test describe consumer group members
+    {
+        // act
+        // the test consumer subscribed to the test topic is still a member of the default consumer group
+        let res = sut
+            .describe_consumer_group(&test_fixture.default_consumer_group, true)
+            .await
+            .expect("Unable to describe the consumer group");
+        // assert
+        assert_eq!(res.state, "Stable");
+        assert_eq!(res.protocol_type, "consumer");
+        assert_eq!(res.members.len(), 1);
+        assert_eq!(
+            res.members[0].assignments,
+            vec![MemberAssignment {
+                topic: test_topic_name.clone(),
+                partitions: vec![0]
+            }]
+        );
+    }
+
+    // test reset consumer group offsets
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        let test_consumer_group = &KafkaTest::get_random_name();
+        KafkaTest::create_topic(&sut, test_topic_name, 2, 1, false)
+            .await
+            .expect("Unable to create a test topic");
+        for i in 0..10 {
+            producer
+                .send(
+                    FutureRecord::to(test_topic_name)
+                        .partition(0)
+                        .payload(&format!("test_message {}", i))
+                        .key(&format!("Key {}", i)),
+                    test_fixture.tmo,
+                )
+                .await
+                .expect("Unable to produce the message");
+        }
+        // act
+        let dry_run = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::Latest,
+                None,
+                true,
+            )
+            .await
+            .expect("Unable to preview the reset of the offsets");
+        let latest = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::Latest,
+                None,
+                false,
+            )
+            .await
+            .expect("Unable to reset the offsets");
+        let shifted = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::ShiftBy(-3),
+                Some(&[0]),
+                false,
+            )
+            .await
+            .expect("Unable to shift the offsets");
+        let explicit = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::Offsets(HashMap::from([(0, 100)])),
+                None,
+                true,
+            )
+            .await
+            .expect("Unable to preview the reset of the offsets");
+        let by_timestamp = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::Timestamps(HashMap::from([(0, 0)])),
+                None,
+                true,
+            )
+            .await
+            .expect("Unable to preview the reset of the offsets");
+        let unknown_partition = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::Offsets(HashMap::from([(0, 5), (7, 10)])),
+                None,
+                true,
+            )
+            .await;
+        let unknown_partition_filter = sut
+            .reset_consumer_group_offsets(
+                test_consumer_group,
+                test_topic_name,
+                &OffsetResetStrategy::Latest,
+                Some(&[0, 7]),
+                true,
+            )
+            .await;
+        // assert
+        let expected_latest = vec![
+            OffsetReset {
+                topic: test_topic_name.clone(),
+                partition_id: 0,
+                current_offset: None,
+                new_offset: 10,
+            },
+            OffsetReset {
+                topic: test_topic_name.clone(),
+                partition_id: 1,
+                current_offset: None,
+                new_offset: 0,
+            },
+        ];
+        assert_eq!(dry_run, expected_latest);
+        assert_eq!(latest, expected_latest);
+        assert_eq!(
+            shifted,
+            vec![OffsetReset {
+                topic: test_topic_name.clone(),
+                partition_id: 0,
+                current_offset: Some(10),
+                new_offset: 7,
+            }]
+        );
+        // the explicit offsets are clamped to the available records
+        assert_eq!(explicit[0].current_offset, Some(7));
+        assert_eq!(explicit[0].new_offset, 10);
+        // only the partitions with a timestamp are reset
+        assert_eq!(by_timestamp.len(), 1);
+        assert_eq!(by_timestamp[0].new_offset, 0);
+        assert!(matches!(unknown_partition, Err(AdminError::InvalidConfiguration(_))));
+        assert!(matches!(
+            unknown_partition_filter,
+            Err(AdminError::InvalidConfiguration(_))
+        ));
+        let res = sut
+            .describe_consumer_group(test_consumer_group, true)
+            .await
+            .expect("Unable to describe the consumer group");
+        let committed: HashMap<_, _> = res
+            .offsets
+            .iter()
+            .filter(|tpo| &tpo.topic == test_topic_name)
+            .map(|tpo| (tpo.partition_id, tpo.offset))
+            .collect();
+        assert_eq!(committed, HashMap::from([(0, 7), (1, 0)]));
+    }
+
+    // test export and import consumer group offsets
+    {
+        // arrange
+        let source_consumer_group = &KafkaTest::get_random_name();
+        let target_consumer_group = &KafkaTest::get_random_name();
+        let test_file = format!("{}/{}", temp_dir().to_str().unwrap(), KafkaTest::get_random_name());
+        sut.set_consumer_group(
+            source_consumer_group,
+            &[&test_topic_name],
+            &crate::core::consumer::types::ConsumerOffsetConfiguration::End,
+        )
+        .expect("Unable to create the consumer group for the test");
+        // act
+        let exported = sut
+            .export_consumer_group_offsets(source_consumer_group, &test_file, OffsetsFileFormat::Csv, false)
+            .await
+            .expect("Unable to export the consumer group offsets");
+        let diff = sut
+            .import_consumer_group_offsets(target_consumer_group, &test_file, OffsetsFileFormat::Csv, true)
+            .await
+            .expect("Unable to preview the import of the consumer group offsets");
+        sut.import_consumer_group_offsets(target_consumer_group, &test_file, OffsetsFileFormat::Csv, false)
+            .await
+            .expect("Unable to import the consumer group offsets");
+        // assert
+        let expected: Vec<_> = exported
+            .offsets
+            .iter()
+            .map(|o| (o.topic.clone(), o.partition_id, None, o.offset))
+            .collect();
+        let diff: Vec<_> = diff
+            .into_iter()
+            .map(|r| (r.topic, r.partition_id, r.current_offset, r.new_offset))
+            .collect();
+        assert_eq!(diff, expected);
+        let imported = sut
+            .describe_consumer_group(target_consumer_group, true)
+            .await
+            .expect("Unable to describe the consumer group");
+        assert_eq!(imported.offsets, exported.offsets);
+    }
+
+    // test import offsets of partitions that don't exist
+    {
+        // arrange
+        let target_consumer_group = &KafkaTest::get_random_name();
+        let test_file = format!("{}/{}", temp_dir().to_str().unwrap(), KafkaTest::get_random_name());
+        fs::write(
+            &test_file,
+            format!("topic;partition;offset\n{test_topic_name};0;1\n{test_topic_name};7;1"),
+        )
+        .expect("Unable to write the offsets file");
+        // act
+        let dry_run = sut
+            .import_consumer_group_offsets(target_consumer_group, &test_file, OffsetsFileFormat::Csv, true)
+            .await;
+        let import = sut
+            .import_consumer_group_offsets(target_consumer_group, &test_file, OffsetsFileFormat::Csv, false)
+            .await;
+        // assert
+        assert!(matches!(dry_run, Err(AdminError::InvalidOffsetsFile(_))));
+        assert!(matches!(import, Err(AdminError::InvalidOffsetsFile(_))));
+        let imported = sut
+            .describe_consumer_group(target_consumer_group, true)
+            .await
+            .expect("Unable to describe the consumer group");
+        assert!(imported.offsets.is_empty());
+    }
+
+    // test consumer group lag
+    {
+        let test_consumer_group = &KafkaTest::get_random_name();
+        sut.set_consumer_group(
+            test_consumer_group,
+            &[&test_topic_name],
+            &crate::core::consumer::types::ConsumerOffsetConfiguration::Beginning,
+        )
+        .expect("Unable to create the consumer group for the test");
+        // act
+        let res = sut
+            .get_consumer_group_lag(test_consumer_group, true)
+            .await
+            .expect("Unable to get the consumer group lag");
+        // assert
+        assert_eq!(res.partitions.len(), 1);
+        let partition = &res.partitions[0];
+        assert_eq!(partition.committed_offset, 0);
+        assert!(partition.lag > 0);
+        assert_eq!(partition.lag, partition.end_offset);
+        assert_eq!(res.total_lag, partition.lag);
+        assert!(partition.time_lag_ms.is_some());
+        assert_eq!(res.max_time_lag_ms, partition.time_lag_ms);
+    }
 }
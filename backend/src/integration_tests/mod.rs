@@ -1,12 +1,18 @@
 #![cfg(test)]
 
 use rdkafka::{config::FromClientConfig, ClientConfig};
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 use uuid::Uuid;
 
-use crate::core::configuration::{ClusterConfig, Favorites};
+use crate::core::{
+    admin::{AdminResult, CreateTopicOptions, KafkaAdmin},
+    configuration::{ClusterConfig, Favorites},
+};
 
+mod acl_admin_it;
+mod cluster_admin_it;
 mod consumer_group_admin_it;
+mod producer_it;
 mod topic_admin_it;
 
 struct KafkaTest {
@@ -45,9 +51,33 @@ impl KafkaTest {
             authentication: crate::core::configuration::AuthenticationConfig::None,
             schema_registry: None,
             favorites: Favorites::default(),
+            record_store: None,
+            protobuf: None,
+            subject_name_strategy: None,
+            kafka_connect: None,
         }
     }
 
+    /// Create a topic setting only the cleanup policy
+    async fn create_topic(
+        admin: &KafkaAdmin,
+        name: &str,
+        num_partitions: i32,
+        isr: i32,
+        compacted: bool,
+    ) -> AdminResult<()> {
+        let options = CreateTopicOptions {
+            configurations: HashMap::from([(
+                "cleanup.policy".to_string(),
+                if compacted { "compact" } else { "delete" }.to_string(),
+            )]),
+            validate_only: false,
+        };
+        admin
+            .create_topic_with_options(name, num_partitions, isr, &options)
+            .await
+    }
+
     fn get_random_name() -> String {
         format!("test_{}", Uuid::new_v4())
     }
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use rdkafka::{
+    consumer::{Consumer, StreamConsumer},
+    message::Headers,
+    Message,
+};
+
+use crate::{
+    core::{
+        admin::KafkaAdmin,
+        parser::Parser,
+        producer::{KafkaProducer, ProduceOptions},
+        types::ParserMode,
+    },
+    integration_tests::KafkaTest,
+};
+
+#[tokio::test]
+async fn test_producer() {
+    // arrange
+    let test_fixture = KafkaTest::default();
+    let consumer: StreamConsumer = test_fixture.build_kafka_client();
+    let cluster_config = test_fixture.build_cluster_config();
+    let admin = KafkaAdmin::new(&cluster_config, test_fixture.tmo).expect("Unable to create the admin client");
+    let sut = KafkaProducer::new(
+        &cluster_config,
+        Arc::new(Parser::new(None, None, Default::default())),
+        test_fixture.tmo,
+    );
+
+    // test produce a record with headers, partition and timestamp
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        KafkaTest::create_topic(&admin, test_topic_name, 3, 1, false)
+            .await
+            .expect("Unable to create the test topic");
+        let options = ProduceOptions {
+            headers: Some(vec![("traceId".into(), Some("abc".into())), ("empty".into(), None)]),
+            partition: Some(2),
+            timestamp: Some(1_600_000_000_000),
+            ..Default::default()
+        };
+        // act
+        let res = sut
+            .produce(test_topic_name, "key", Some("value"), ParserMode::String, &options)
+            .await;
+        // assert
+        let delivery_report = res.expect("Unable to produce the record");
+        assert_eq!(delivery_report.partition, 2);
+        assert_eq!(delivery_report.offset, 0);
+
+        consumer
+            .subscribe(&[test_topic_name])
+            .expect("Unable to subscribe to the test topic");
+        let consumed = consumer.stream().next().await.unwrap().expect("Unable to consume");
+        assert_eq!(consumed.partition(), 2);
+        assert_eq!(consumed.timestamp().to_millis(), Some(1_600_000_000_000));
+        let headers = consumed.headers().expect("Missing headers");
+        assert_eq!(headers.count(), 2);
+        assert_eq!(headers.get(0).key, "traceId");
+        assert_eq!(headers.get(0).value, Some("abc".as_bytes()));
+        assert_eq!(headers.get(1).value, None);
+    }
+}
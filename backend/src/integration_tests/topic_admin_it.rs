@@ -4,9 +4,15 @@ use rdkafka::{
     consumer::{stream_consumer::StreamConsumer, Consumer},
     producer::{FutureProducer, FutureRecord},
 };
-use std::{collections::HashSet, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
-use crate::{core::admin::KafkaAdmin, integration_tests::KafkaTest};
+use crate::{
+    core::admin::{AdminError, CreateTopicOptions, DeleteRecordsConfiguration, DeletedRecords, KafkaAdmin},
+    integration_tests::KafkaTest,
+};
 
 #[tokio::test]
 async fn test_topic_admin_client() {
@@ -23,7 +29,7 @@ async fn test_topic_admin_client() {
         let test_topic_name = &KafkaTest::get_random_name();
         let partition_count = 7_usize;
         // act
-        sut.create_topic(test_topic_name, partition_count as i32, 1, false)
+        KafkaTest::create_topic(&sut, test_topic_name, partition_count as i32, 1, false)
             .await
             .expect("Unable to create the test topic");
 
@@ -40,10 +46,69 @@ async fn test_topic_admin_client() {
         assert!(tp.contains(&(test_topic_name, partition_count)))
     }
 
+    // test create a topic with configurations
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        let options = CreateTopicOptions {
+            configurations: HashMap::from([
+                ("cleanup.policy".to_string(), "compact".to_string()),
+                ("retention.ms".to_string(), "3600000".to_string()),
+            ]),
+            validate_only: false,
+        };
+        // act
+        sut.create_topic_with_options(test_topic_name, 1, 1, &options)
+            .await
+            .expect("Unable to create the test topic");
+        // assert
+        let res = sut
+            .get_topic_configuration(test_topic_name)
+            .await
+            .expect("Unable to retrieve the topic configuration");
+        assert_eq!(res.get("retention.ms").unwrap(), &Some("3600000".to_string()));
+        assert_eq!(res.get("cleanup.policy").unwrap(), &Some("compact".to_string()));
+    }
+
+    // test validate only and invalid configurations
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        let valid_options = CreateTopicOptions {
+            configurations: HashMap::from([("retention.ms".to_string(), "3600000".to_string())]),
+            validate_only: true,
+        };
+        let invalid_options = CreateTopicOptions {
+            configurations: HashMap::from([
+                ("retention.ms".to_string(), "3600000".to_string()),
+                ("cleanup.policy".to_string(), "not-a-policy".to_string()),
+            ]),
+            validate_only: true,
+        };
+        // act
+        let valid_res = sut
+            .create_topic_with_options(test_topic_name, 1, 1, &valid_options)
+            .await;
+        let invalid_res = sut
+            .create_topic_with_options(test_topic_name, 1, 1, &invalid_options)
+            .await;
+        // assert
+        assert!(valid_res.is_ok());
+        let topics = sut.list_topics().await.expect("Unable to retrieve the list of topics");
+        assert!(!topics.iter().any(|t| &t.name == test_topic_name));
+        match invalid_res {
+            Err(AdminError::InvalidConfiguration(message)) => {
+                assert!(message.contains("cleanup.policy"));
+                assert!(!message.contains("retention.ms"));
+            }
+            _ => panic!("Expected an invalid configuration error"),
+        }
+    }
+
     // test retrieve the list of topics
     {
         let test_topic_name = &KafkaTest::get_random_name();
-        sut.create_topic(test_topic_name, 1, 1, true)
+        KafkaTest::create_topic(&sut, test_topic_name, 1, 1, true)
             .await
             .expect("Unable to create a test topic");
         // act
@@ -68,7 +133,7 @@ async fn test_topic_admin_client() {
         // arrange
         let test_topic_name = &KafkaTest::get_random_name();
         let partition_count = 3_usize;
-        sut.create_topic(test_topic_name, partition_count as i32, 1, true)
+        KafkaTest::create_topic(&sut, test_topic_name, partition_count as i32, 1, true)
             .await
             .expect("Unable to create a test topic");
         // act
@@ -83,7 +148,7 @@ async fn test_topic_admin_client() {
         // arrange
         let test_topic_name = &KafkaTest::get_random_name();
         let partition_count = 3_usize;
-        sut.create_topic(test_topic_name, partition_count as i32, 1, true)
+        KafkaTest::create_topic(&sut, test_topic_name, partition_count as i32, 1, true)
             .await
             .expect("Unable to create a test topic");
         // act
@@ -95,13 +160,67 @@ async fn test_topic_admin_client() {
             res.configurations.get("cleanup.policy").unwrap(),
             &Some("compact".to_string())
         );
+        for p in res.partitions {
+            assert!(p.leader.is_some());
+            assert_eq!(p.replica_ids.len(), p.replicas);
+            assert_eq!(p.isr_ids.len(), p.isr);
+            assert!(p.replica_ids.contains(&p.leader.unwrap()));
+            assert!(!p.under_replicated);
+        }
+    }
+
+    // alter topic configuration
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        KafkaTest::create_topic(&sut, test_topic_name, 1, 1, true)
+            .await
+            .expect("Unable to create a test topic");
+        let configurations = HashMap::from([
+            ("retention.ms".to_string(), Some("3600000".to_string())),
+            ("segment.bytes".to_string(), Some("1048576".to_string())),
+        ]);
+        // act
+        sut.alter_topic_configuration(test_topic_name, &configurations)
+            .await
+            .expect("Unable to alter the topic configuration");
+        // assert
+        let res = sut
+            .get_topic_configuration(test_topic_name)
+            .await
+            .expect("Unable to retrieve the topic configuration");
+        assert_eq!(res.get("retention.ms").unwrap(), &Some("3600000".to_string()));
+        assert_eq!(res.get("segment.bytes").unwrap(), &Some("1048576".to_string()));
+        // the configurations not specified are preserved
+        assert_eq!(res.get("cleanup.policy").unwrap(), &Some("compact".to_string()));
+        // unknown configurations are rejected
+        let invalid = HashMap::from([("not.a.config".to_string(), Some("1".to_string()))]);
+        assert!(sut.alter_topic_configuration(test_topic_name, &invalid).await.is_err());
+    }
+
+    // create partitions
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        KafkaTest::create_topic(&sut, test_topic_name, 2, 1, false)
+            .await
+            .expect("Unable to create a test topic");
+        // act
+        sut.create_partitions(test_topic_name, 5)
+            .await
+            .expect("Unable to create the partitions");
+        // assert
+        let res = sut.get_topic(test_topic_name).expect("Unable to get topic");
+        assert_eq!(res.partitions.len(), 5);
+        // the partition count can't be decreased
+        assert!(sut.create_partitions(test_topic_name, 3).await.is_err());
     }
 
     // delete topic test
     {
         // arrange
         let test_topic_name = &KafkaTest::get_random_name();
-        sut.create_topic(test_topic_name, 1, 1, true)
+        KafkaTest::create_topic(&sut, test_topic_name, 1, 1, true)
             .await
             .expect("Unable to create a test topic");
         // act
@@ -110,13 +229,59 @@ async fn test_topic_admin_client() {
         assert!(res.is_ok())
     }
 
+    // delete records
+    {
+        // arrange
+        let test_topic_name = &KafkaTest::get_random_name();
+        let producer: FutureProducer = test_fixture.build_kafka_client();
+        KafkaTest::create_topic(&sut, test_topic_name, 1, 1, false)
+            .await
+            .expect("Unable to create a test topic");
+        for i in 0..10 {
+            producer
+                .send(
+                    FutureRecord::to(test_topic_name)
+                        .payload(&format!("test_message {}", i))
+                        .key(&format!("Key {}", i)),
+                    Duration::from_secs(0),
+                )
+                .await
+                .expect("Unable to produce the message");
+        }
+        let configuration = DeleteRecordsConfiguration::Offsets(HashMap::from([(0, 4)]));
+        // act
+        let preview = sut
+            .preview_delete_records(test_topic_name, &configuration)
+            .await
+            .expect("Unable to preview the records deletion");
+        let deleted = sut
+            .delete_records(test_topic_name, &configuration)
+            .await
+            .expect("Unable to delete the records");
+        let preview_all = sut
+            .preview_delete_records(test_topic_name, &DeleteRecordsConfiguration::All)
+            .await
+            .expect("Unable to preview the records deletion");
+        // assert
+        let expected = vec![DeletedRecords {
+            partition_id: 0,
+            low_offset: 0,
+            before_offset: 4,
+            records_count: 4,
+        }];
+        assert_eq!(preview, expected);
+        assert_eq!(deleted, expected);
+        assert_eq!(preview_all[0].low_offset, 4);
+        assert_eq!(preview_all[0].records_count, 6);
+    }
+
     // get last offset
     {
         // arrange
         let expected_offset = 13;
         let test_topic_name = &KafkaTest::get_random_name();
         let producer: FutureProducer = test_fixture.build_kafka_client();
-        sut.create_topic(test_topic_name, 1, 1, true)
+        KafkaTest::create_topic(&sut, test_topic_name, 1, 1, true)
             .await
             .expect("Unable to create a test topic");
         // produce some records to increase the offset
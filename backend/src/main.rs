@@ -10,14 +10,27 @@ mod telemetry;
 
 use crate::api::{
     admin::{
-        create_topic, delete_consumer_group, delete_topic, describe_consumer_group, get_consumer_group_state,
-        get_last_offsets, get_topic_info, list_consumer_groups, list_topics, set_consumer_group,
+        alter_topic_configuration, create_acls, create_partitions, create_topic, delete_acls, delete_consumer_group,
+        delete_records, delete_topic, describe_acls, describe_cluster, describe_consumer_group,
+        export_consumer_group_offsets, get_broker_configuration, get_consumer_group_lag, get_consumer_group_state,
+        get_last_offsets, get_topic_info, import_consumer_group_offsets, list_consumer_groups, list_topics,
+        preview_delete_records, reset_consumer_group_offsets, set_consumer_group,
     },
     configuration::{get_configuration, write_configuration},
-    consumer::{export_records, get_consumer_state, get_records_page, start_consumer, stop_consumer},
+    consumer::{
+        delete_stored_topic, export_records, get_consumer_state, get_records_page, list_stored_topics, start_consumer,
+        stop_consumer,
+    },
+    kafka_connect::{
+        delete_connector, get_connector_config, get_connector_status, list_connectors, pause_connector,
+        restart_connector, restart_connector_task, resume_connector, update_connector_config,
+    },
     producer::produce_record,
     schema_registry::{
-        delete_subject, delete_subject_version, get_subject, list_subjects, post_schema, set_compatibility_level,
+        check_schema_compatibility, delete_subject, delete_subject_version, diff_schema_versions,
+        get_global_compatibility_level, get_schema_registry_mode, get_subject, list_deleted_subject_versions,
+        list_deleted_subjects, list_subjects, post_schema, set_compatibility_level, set_global_compatibility_level,
+        set_schema_registry_mode,
     },
     utils::{export_datastore, parse_keystore, parse_truststore},
 };
@@ -44,28 +57,62 @@ fn main() {
             get_consumer_state,
             get_records_page,
             export_records,
+            list_stored_topics,
+            delete_stored_topic,
             // schema
             list_subjects,
             get_subject,
             delete_subject,
             delete_subject_version,
             post_schema,
+            check_schema_compatibility,
+            diff_schema_versions,
             set_compatibility_level,
+            get_global_compatibility_level,
+            set_global_compatibility_level,
+            get_schema_registry_mode,
+            set_schema_registry_mode,
+            list_deleted_subjects,
+            list_deleted_subject_versions,
             // config
             get_configuration,
             write_configuration,
+            // admin cluster
+            describe_cluster,
+            describe_acls,
+            create_acls,
+            delete_acls,
+            get_broker_configuration,
             // admin topics
             list_topics,
             get_topic_info,
             create_topic,
             delete_topic,
+            alter_topic_configuration,
+            create_partitions,
+            preview_delete_records,
+            delete_records,
             get_last_offsets,
             // admin consumer groups
             get_consumer_group_state,
             list_consumer_groups,
             describe_consumer_group,
+            get_consumer_group_lag,
             set_consumer_group,
+            reset_consumer_group_offsets,
+            export_consumer_group_offsets,
+            import_consumer_group_offsets,
             delete_consumer_group,
+            // kafka connect
+            list_connectors,
+            get_connector_status,
+            get_connector_config,
+            update_connector_config,
+            pause_connector,
+            resume_connector,
+            restart_connector,
+            restart_connector_task,
+            delete_connector,
         ])
         .setup(|app| {
             app.manage(AppState::new(app.app_handle()));
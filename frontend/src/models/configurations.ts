@@ -16,6 +16,34 @@ export type Cluster = {
   authentication: ClusterAuthentication;
   schemaRegistry: SchemaRegistry | null;
   favorites: Favorites;
+  recordStore?: RecordStore;
+  protobuf?: ProtobufConfig;
+  subjectNameStrategy?: SubjectNameStrategyConfig;
+  kafkaConnect?: KafkaConnect;
+};
+
+export type SubjectNameStrategy = "TopicNameStrategy" | "RecordNameStrategy" | "TopicRecordNameStrategy";
+
+/** subject naming strategy of the produced records */
+export type SubjectNameStrategyConfig = {
+  /** strategy of the topics not matching any pattern */
+  default?: SubjectNameStrategy;
+  /** the first pattern matching the whole topic name is used */
+  topicPatterns?: { pattern: string; strategy: SubjectNameStrategy }[];
+};
+
+/** local protobuf schemas */
+export type ProtobufConfig = {
+  /** paths to .desc descriptor sets or .proto files */
+  files: string[];
+  /** fully qualified message name to use for each topic */
+  topicMessageTypes: Record<string, string>;
+};
+
+/** on disk store for the consumed records */
+export type RecordStore = {
+  location: string;
+  maxSizeMb?: number;
 };
 
 export type ClusterAuthentication =
@@ -36,6 +64,13 @@ export type SchemaRegistry = {
   password?: string;
 };
 
+/** REST api of the Kafka Connect cluster */
+export type KafkaConnect = {
+  endpoint: string;
+  username?: string;
+  password?: string;
+};
+
 export type Favorites = {
   topics: string[];
   schemas: string[];
@@ -1,3 +1,4 @@
 export * from "./kafka";
 export * from "./configurations";
 export * from "./schema-registry";
+export * from "./kafka-connect";
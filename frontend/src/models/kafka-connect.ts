@@ -0,0 +1,18 @@
+export type ConnectorStatus = {
+  name: string;
+  /** "source" or "sink", null if not reported by the worker */
+  type: string | null;
+  connector: ConnectorState;
+  tasks: TaskState[];
+};
+
+export type ConnectorState = {
+  state: ConnectorStateName;
+  workerId: string;
+  /** stack trace of the failure, if any */
+  trace: string | null;
+};
+
+export type TaskState = ConnectorState & { id: number };
+
+export type ConnectorStateName = "UNASSIGNED" | "RUNNING" | "PAUSED" | "FAILED" | "RESTARTING";
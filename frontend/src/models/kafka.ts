@@ -1,3 +1,20 @@
+export type ClusterInfo = {
+  controllerId?: number;
+  brokers: BrokerInfo[];
+  topicsCount: number;
+  partitionsCount: number;
+  underReplicatedPartitions: number;
+  offlinePartitions: number;
+};
+
+export type BrokerInfo = {
+  id: number;
+  host: string;
+  port: number;
+  rack?: string;
+  isController: boolean;
+};
+
 export type TopicInfo = {
   name: string;
   partitions: PartitionInfo[];
@@ -12,13 +29,16 @@ export type KafkaRecord = {
   offset: number;
   timestamp?: number;
   schema_id?: number;
+  key_schema_id?: number;
   record_bytes: number;
-  header?: string;
+  /** JSON array of [key, value] pairs in the record order */
+  headers?: string;
 };
 
 export type ConsumerState = {
   isRunning: boolean;
   recordCount: number;
+  isCompleted: boolean;
 };
 
 export type ConsumerOffsetConfiguration =
@@ -31,18 +51,112 @@ export type ConsumerOffsetConfiguration =
         /** unix timestamp in ms */
         stop_timestamp?: number;
       };
+    }
+  | {
+      Offsets: {
+        /** start offset by topic partition */
+        start_offsets: { topic: string; partition_id: number; offset: number }[];
+        /** stop offset (exclusive) by topic partition */
+        stop_offsets?: { topic: string; partition_id: number; offset: number }[];
+      };
     };
 
 export type ConsumerConfiguration = {
   compactify: boolean;
   consumer_start_config: ConsumerOffsetConfiguration;
+  /** consume only the specified partition ids */
+  partitions?: number[];
+  /** stop the consumer once the end offsets at start time are reached */
+  snapshot?: boolean;
+  /** parser mode of key and value, detected automatically if not set */
+  parserModes?: { key?: ParserMode; value?: ParserMode };
+};
+
+export type ParserMode = "Avro" | "Protobuf" | "Json" | "String";
+
+export type DeleteRecordsConfiguration =
+  | "All"
+  /** unix timestamp in ms */
+  | { Timestamp: number }
+  /** delete the records before the offset (exclusive) by partition id */
+  | { Offsets: Record<number, number> };
+
+export type DeletedRecords = {
+  partitionId: number;
+  lowOffset: number;
+  beforeOffset: number;
+  recordsCount: number;
+};
+
+export type OffsetResetStrategy =
+  | "Earliest"
+  | "Latest"
+  /** explicit offset by partition id, the partitions not specified are not affected */
+  | { Offsets: Record<number, number> }
+  /** move the committed offset by N records, backwards if negative */
+  | { ShiftBy: number }
+  /** unix timestamp in ms */
+  | { Timestamp: number }
+  /** unix timestamp in ms by partition id, the partitions not specified are not affected */
+  | { Timestamps: Record<number, number> }
+  | "Current";
+
+export type OffsetReset = {
+  topic: string;
+  partitionId: number;
+  /** null if the consumer group never committed on the partition */
+  currentOffset: number | null;
+  newOffset: number;
+};
+
+export type ConsumerGroupOffsets = {
+  consumerGroup: string;
+  offsets: TopicPartitionOffset[];
 };
 
+export type OffsetsFileFormat = "Json" | "Csv";
+
 export type ConsumerGroupInfo = {
   name: string;
+  state: string;
+  /** "consumer" for the kafka consumers */
+  protocolType: string;
+  /** partition assignor used by the group */
+  protocol: string;
+  members: ConsumerGroupMember[];
   offsets: TopicPartitionOffset[];
 };
 
+export type ConsumerGroupMember = {
+  id: string;
+  clientId: string;
+  host: string;
+  assignments: MemberAssignment[];
+};
+
+export type MemberAssignment = {
+  topic: string;
+  partitions: number[];
+};
+
+export type ConsumerGroupLag = {
+  name: string;
+  totalLag: number;
+  /** highest time lag across the partitions in ms */
+  maxTimeLagMs: number | null;
+  partitions: PartitionLag[];
+};
+
+export type PartitionLag = {
+  topic: string;
+  partitionId: number;
+  committedOffset: number;
+  endOffset: number;
+  lag: number;
+  /** ms elapsed since the record at the committed offset was produced */
+  timeLagMs: number | null;
+};
+
 export type TopicPartitionOffset = {
   topic: string;
   partition_id: number;
@@ -55,8 +169,50 @@ export type PartitionInfo = {
   isr: number;
   replicas: number;
   last_offset: number;
+  /** broker id of the leader, missing if the partition is offline */
+  leader?: number;
+  replicaIds: number[];
+  isrIds: number[];
+  underReplicated: boolean;
 };
 export type PartitionOffset = {
   partitionId: number;
   offset: number;
 };
+
+export type AclResourceType = "Unknown" | "Any" | "Topic" | "Group" | "Cluster";
+
+/** Match is only valid in the filters, it matches literal, wildcard and prefixed bindings affecting the resource */
+export type AclPatternType = "Unknown" | "Any" | "Match" | "Literal" | "Prefixed";
+
+export type AclOperation =
+  | "Unknown"
+  | "Any"
+  | "All"
+  | "Read"
+  | "Write"
+  | "Create"
+  | "Delete"
+  | "Alter"
+  | "Describe"
+  | "ClusterAction"
+  | "DescribeConfigs"
+  | "AlterConfigs"
+  | "IdempotentWrite";
+
+export type AclPermissionType = "Unknown" | "Any" | "Deny" | "Allow";
+
+export type AclBinding = {
+  resourceType: AclResourceType;
+  /** "*" for all the resources of the type */
+  resourceName: string;
+  patternType: AclPatternType;
+  /** e.g. User:alice */
+  principal: string;
+  host: string;
+  operation: AclOperation;
+  permissionType: AclPermissionType;
+};
+
+/** the fields not specified match any value */
+export type AclBindingFilter = Partial<AclBinding>;
@@ -8,4 +8,43 @@ export type SchemaVersion = {
   id: number;
   version: number;
   schema: string;
+  schemaType: "AVRO" | "PROTOBUF" | "JSON";
+  references: SchemaReference[];
 };
+
+/** type defined in another subject */
+export type SchemaReference = {
+  /** fully qualified name of the type for avro, import path for protobuf */
+  name: string;
+  subject: string;
+  version: number;
+};
+
+export type CompatibilityCheck = {
+  isCompatible: boolean;
+  /** reasons of the incompatibility, empty if compatible */
+  messages: string[];
+};
+
+/** structural change between two versions of an avro schema */
+export type SchemaChange = {
+  /** dot separated path of the changed element, starting from the root schema name */
+  path: string;
+  kind: SchemaChangeKind;
+  /** the new schema can read the data written with the old one */
+  backwardCompatible: boolean;
+  /** the old schema can read the data written with the new one */
+  forwardCompatible: boolean;
+};
+
+export type SchemaChangeKind =
+  | "FieldAdded"
+  | "FieldRemoved"
+  | { FieldRenamed: { from: string } }
+  | { TypeChanged: { from: string; to: string } }
+  | { DefaultChanged: { from: unknown; to: unknown } }
+  | { EnumSymbolsAdded: { symbols: string[] } }
+  | { EnumSymbolsRemoved: { symbols: string[] } };
+
+/** operations allowed on the registry or on a subject, IMPORT allows to register schemas with explicit ids */
+export type SchemaRegistryMode = "READWRITE" | "READONLY" | "IMPORT";
@@ -171,6 +171,16 @@ export const ClusterForm = ({ onSubmit, initialValues }: ClusterFormProps) => {
           <TextInput label="Endpoint" placeholder="localhost:9091" {...form.getInputProps("schemaRegistry.endpoint")} />
           <TextInput label="Username" placeholder="username" {...form.getInputProps("schemaRegistry.username")} />
           <PasswordInput label="Password" placeholder="password" {...form.getInputProps("schemaRegistry.password")} />
+          <Title mt={10} order={3}>
+            Kafka Connect
+          </Title>
+          <TextInput
+            label="Endpoint"
+            placeholder="http://localhost:8083"
+            {...form.getInputProps("kafkaConnect.endpoint")}
+          />
+          <TextInput label="Username" placeholder="username" {...form.getInputProps("kafkaConnect.username")} />
+          <PasswordInput label="Password" placeholder="password" {...form.getInputProps("kafkaConnect.password")} />
         </Stack>
       </ScrollArea>
       <Group my={20} position="right">
@@ -26,6 +26,12 @@ export type SchemaRegistryFormType = {
   password: string;
 };
 
+export type KafkaConnectFormType = {
+  endpoint: string;
+  username?: string;
+  password: string;
+};
+
 export type ClusterFormType = {
   name: string;
   endpoint: string;
@@ -36,4 +42,5 @@ export type ClusterFormType = {
     jks?: JksFormType;
   };
   schemaRegistry?: SchemaRegistryFormType;
+  kafkaConnect?: KafkaConnectFormType;
 };
@@ -22,6 +22,7 @@ export const useClusterForm = (initialValues?: ClusterFormType) => {
         jks: { keystoreLocation: "", truststoreLocation: "" },
       },
       schemaRegistry: { endpoint: "", username: "", password: "" },
+      kafkaConnect: { endpoint: "", username: "", password: "" },
     },
     validate: {
       name: (v) => nonEmptyValidation("Cluster")(v ?? ""),
@@ -55,6 +56,16 @@ export const useClusterForm = (initialValues?: ClusterFormType) => {
           }
         },
       },
+      kafkaConnect: {
+        password: (v: string, values: unknown) => {
+          const form = values as ClusterFormType;
+          if (!form.kafkaConnect) return null;
+          const { endpoint, username } = form.kafkaConnect;
+          if ((endpoint ?? "").length > 0 && (username ?? "").length > 0 && (v ?? "").length == 0) {
+            return "Kafka Connect password must be set in order to use Basic authentication.";
+          }
+        },
+      },
     },
   });
   return form;
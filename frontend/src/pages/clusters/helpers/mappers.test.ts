@@ -20,6 +20,7 @@ describe("mapClusterToForm", () => {
         username: "admin",
         password: "admin",
       },
+      kafkaConnect: { endpoint: "http://localhost:8083" },
     } as unknown as Cluster;
 
     const expectedForm = {
@@ -44,6 +45,11 @@ describe("mapClusterToForm", () => {
         username: "admin",
         password: "admin",
       },
+      kafkaConnect: {
+        endpoint: "http://localhost:8083",
+        username: "",
+        password: "",
+      },
     };
 
     expect(mapClusterToForm(cluster)).toEqual(expectedForm);
@@ -89,6 +95,11 @@ describe("mapClusterToForm", () => {
         username: "admin",
         password: "admin",
       },
+      kafkaConnect: {
+        endpoint: "",
+        username: "",
+        password: "",
+      },
     };
     expect(mapClusterToForm(cluster)).toEqual(expectedForm);
   });
@@ -134,6 +145,11 @@ describe("mapClusterToForm", () => {
         username: "admin",
         password: "admin",
       },
+      kafkaConnect: {
+        endpoint: "",
+        username: "",
+        password: "",
+      },
     };
 
     expect(mapClusterToForm(cluster)).toEqual(expectedForm);
@@ -172,6 +188,11 @@ describe("mapFormToCluster", () => {
         username: "admin",
         password: "admin",
       },
+      kafkaConnect: {
+        endpoint: "http://localhost:8083",
+        username: "",
+        password: "",
+      },
     } as unknown as ClusterFormType;
 
     const expectedCluster = {
@@ -184,6 +205,11 @@ describe("mapFormToCluster", () => {
         username: "admin",
         password: "admin",
       },
+      kafkaConnect: {
+        endpoint: "http://localhost:8083",
+        username: undefined,
+        password: undefined,
+      },
       favorites: {
         consumers: [],
         schemas: [],
@@ -28,6 +28,11 @@ export function mapClusterToForm(cluster?: Cluster): ClusterFormType | undefined
       password: cluster.schemaRegistry?.password ?? "",
       username: cluster.schemaRegistry?.username ?? "",
     },
+    kafkaConnect: {
+      endpoint: cluster.kafkaConnect?.endpoint ?? "",
+      password: cluster.kafkaConnect?.password ?? "",
+      username: cluster.kafkaConnect?.username ?? "",
+    },
   };
 }
 
@@ -66,6 +71,14 @@ export async function mapFormToCluster(c: ClusterFormType): Promise<Cluster> {
             password: nonEmptyOrUndefined(c.schemaRegistry.password),
           }
         : null,
+    kafkaConnect:
+      c.kafkaConnect && c.kafkaConnect.endpoint
+        ? {
+            ...c.kafkaConnect,
+            username: nonEmptyOrUndefined(c.kafkaConnect.username),
+            password: nonEmptyOrUndefined(c.kafkaConnect.password),
+          }
+        : undefined,
     favorites: {
       consumers: [],
       schemas: [],
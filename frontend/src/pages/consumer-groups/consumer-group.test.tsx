@@ -11,10 +11,14 @@ vi.mock("../../tauri/admin", () => ({
   describeConsumerGroup: () =>
     Promise.resolve({
       name: "test",
+      state: "Stable",
+      protocolType: "consumer",
+      protocol: "range",
+      members: [],
       offsets: [],
     }),
   getConsumerGroupState: () => Promise.resolve("Stable"),
-  getLastOffsets: vi.fn(),
+  getConsumerGroupLag: () => Promise.resolve({ name: "test", totalLag: 0, maxTimeLagMs: null, partitions: [] }),
   getConsumerGroups: () => Promise.resolve([]),
 }));
 
@@ -34,12 +38,23 @@ describe("ConsumerGroupTopicDetails", () => {
     const queryClient = new QueryClient();
     const { container } = render(
       <ConsumerGroupTopicDetails
-        clusterId={""}
         topicName="topicName"
         offsets={[
           { partition: 0, offset: 0 },
           { partition: 1, offset: 10 },
         ]}
+        lag={[
+          { topic: "topicName", partitionId: 0, committedOffset: 0, endOffset: 5, lag: 5, timeLagMs: 2_000 },
+          { topic: "topicName", partitionId: 1, committedOffset: 10, endOffset: 10, lag: 0, timeLagMs: null },
+        ]}
+        members={[
+          {
+            id: "consumer-1-id",
+            clientId: "consumer-1",
+            host: "/127.0.0.1",
+            assignments: [{ topic: "topicName", partitions: [0, 1] }],
+          },
+        ]}
       />,
       {
         wrapper: ({ children }) => (
@@ -3,7 +3,9 @@ import { useQuery } from "@tanstack/react-query";
 import React from "react";
 import { useMemo } from "react";
 import { PageHeader } from "../../components";
-import { describeConsumerGroup, getConsumerGroupState, getLastOffsets } from "../../tauri/admin";
+import { parseMsToHumanReadable } from "../../helpers/human-readable";
+import { ConsumerGroupMember, PartitionLag } from "../../models/kafka";
+import { describeConsumerGroup, getConsumerGroupLag, getConsumerGroupState } from "../../tauri/admin";
 import { ToolsMenu } from "./tools-menu";
 
 type ConsumerGroupProps = {
@@ -26,6 +28,12 @@ export const ConsumerGroup = (props: ConsumerGroupProps) => {
     refetchOnWindowFocus: false,
     refetchOnMount: false,
   });
+  // the lag is computed against the offsets just retrieved, so it is refreshed with them
+  const { data: consumerGroupLag } = useQuery(
+    ["getConsumerGroupLag", clusterId, name, consumerGroupInfo],
+    () => getConsumerGroupLag(clusterId, name, false),
+    { enabled: consumerGroupInfo !== undefined, refetchOnWindowFocus: false },
+  );
   const topicOffsetMap = useMemo(() => {
     if (!consumerGroupInfo) return;
     const map = consumerGroupInfo.offsets.reduce(
@@ -45,7 +53,9 @@ export const ConsumerGroup = (props: ConsumerGroupProps) => {
     <Container fluid>
       <PageHeader
         title={name}
-        subtitle={`topics: ${topicOffsetMap?.length ?? "..."}, status: ${consumerGroupState ?? "..."}`}>
+        subtitle={`topics: ${topicOffsetMap?.length ?? "..."}, status: ${consumerGroupState ?? "..."}, members: ${
+          consumerGroupInfo?.members.length ?? "..."
+        }, lag: ${consumerGroupLag?.totalLag ?? "..."}`}>
         {consumerGroupInfo && (
           <ToolsMenu
             loading={isLoading}
@@ -70,7 +80,13 @@ export const ConsumerGroup = (props: ConsumerGroupProps) => {
               sx={{ overflowX: "hidden", overflowY: "scroll", width: "100%", height: "calc(100vh - 180px)" }}>
               <Accordion chevronPosition="left" variant="contained" defaultValue="customization">
                 {topicOffsetMap.map(([topic, details]) => (
-                  <ConsumerGroupTopicDetails key={topic} clusterId={clusterId} topicName={topic} offsets={details} />
+                  <ConsumerGroupTopicDetails
+                    key={topic}
+                    topicName={topic}
+                    offsets={details}
+                    lag={consumerGroupLag?.partitions.filter((p) => p.topic === topic)}
+                    members={consumerGroupInfo?.members}
+                  />
                 ))}
               </Accordion>
             </Container>
@@ -81,24 +97,27 @@ export const ConsumerGroup = (props: ConsumerGroupProps) => {
   );
 };
 
+const formatTimeLag = (timeLagMs: number | null | undefined): string => {
+  if (timeLagMs === undefined) return "...";
+  if (timeLagMs === null) return "-";
+  return parseMsToHumanReadable(timeLagMs) || "(< 1 second)";
+};
+
 export const ConsumerGroupTopicDetails = ({
-  clusterId,
   topicName,
   offsets,
+  lag,
+  members,
 }: {
-  clusterId: string;
   topicName: string;
   offsets: { partition: number; offset: number }[];
+  lag?: PartitionLag[];
+  members?: ConsumerGroupMember[];
 }) => {
-  const { data } = useQuery(["getLastOffsets", clusterId, topicName, offsets], async () => {
-    const lastOffsets = (await getLastOffsets(clusterId, [topicName]))[topicName];
-    const sumLastOffsets = lastOffsets.map((po) => po.offset).reduce((a, b) => a + b, 0);
-    const sumOffsets = offsets.map((o) => o.offset).reduce((a, b) => a + b, 0);
-    return {
-      lastOffsets,
-      totalLag: sumLastOffsets - sumOffsets,
-    };
-  });
+  // the member currently assigned to the partition, if any
+  const getOwner = (partition: number) =>
+    members?.find((m) => m.assignments.some((a) => a.topic === topicName && a.partitions.includes(partition)));
+  const totalLag = lag?.map((p) => p.lag).reduce((a, b) => a + b, 0);
   return (
     <Accordion.Item key={topicName} value={topicName}>
       <Accordion.Control>
@@ -107,13 +126,13 @@ export const ConsumerGroupTopicDetails = ({
             {topicName}
           </Text>
           <Text italic size={"md"}>
-            Lag: {data?.totalLag ?? "..."}
+            Lag: {totalLag ?? "..."}
           </Text>
         </Group>
       </Accordion.Control>
       <Accordion.Panel>
         <Grid>
-          <Grid.Col span={6}>
+          <Grid.Col span={2}>
             <Text align="left" weight={"bold"}>
               Topic
             </Text>
@@ -133,22 +152,44 @@ export const ConsumerGroupTopicDetails = ({
               Lag
             </Text>
           </Grid.Col>
-          {offsets.map(({ offset, partition }) => (
-            <React.Fragment key={`${topicName}-${partition}`}>
-              <Grid.Col span={6}>
-                <Text sx={{ overflowWrap: "break-word" }}>{topicName}</Text>
-              </Grid.Col>
-              <Grid.Col span={2}>
-                <Text>{partition}</Text>
-              </Grid.Col>
-              <Grid.Col span={2}>
-                <Text>{offset}</Text>
-              </Grid.Col>
-              <Grid.Col span={2}>
-                <Text>{(data?.lastOffsets.find((po) => po.partitionId === partition)?.offset ?? 0) - offset}</Text>
-              </Grid.Col>
-            </React.Fragment>
-          ))}
+          <Grid.Col span={2}>
+            <Text align="left" weight={"bold"}>
+              Time lag
+            </Text>
+          </Grid.Col>
+          <Grid.Col span={2}>
+            <Text align="left" weight={"bold"}>
+              Owner
+            </Text>
+          </Grid.Col>
+          {offsets.map(({ offset, partition }) => {
+            const partitionLag = lag?.find((p) => p.partitionId === partition);
+            const owner = getOwner(partition);
+            return (
+              <React.Fragment key={`${topicName}-${partition}`}>
+                <Grid.Col span={2}>
+                  <Text sx={{ overflowWrap: "break-word" }}>{topicName}</Text>
+                </Grid.Col>
+                <Grid.Col span={2}>
+                  <Text>{partition}</Text>
+                </Grid.Col>
+                <Grid.Col span={2}>
+                  <Text>{offset}</Text>
+                </Grid.Col>
+                <Grid.Col span={2}>
+                  <Text>{partitionLag?.lag ?? "..."}</Text>
+                </Grid.Col>
+                <Grid.Col span={2}>
+                  <Text>{formatTimeLag(lag ? partitionLag?.timeLagMs ?? null : undefined)}</Text>
+                </Grid.Col>
+                <Grid.Col span={2}>
+                  <Text sx={{ overflowWrap: "break-word" }} title={owner?.id}>
+                    {owner ? `${owner.clientId} (${owner.host})` : "-"}
+                  </Text>
+                </Grid.Col>
+              </React.Fragment>
+            );
+          })}
         </Grid>
       </Accordion.Panel>
     </Accordion.Item>
@@ -11,6 +11,10 @@ describe("ToolsMenu", () => {
         clusterId={""}
         data={{
           name: "",
+          state: "Empty",
+          protocolType: "consumer",
+          protocol: "",
+          members: [],
           offsets: [],
         }}
         onRefresh={vi.fn()}
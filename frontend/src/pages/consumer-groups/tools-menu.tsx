@@ -1,9 +1,25 @@
-import { Text, Loader, Menu, ActionIcon, Modal } from "@mantine/core";
+import { Text, Loader, Menu, ActionIcon, Modal, Table, ScrollArea } from "@mantine/core";
 import { openConfirmModal } from "@mantine/modals";
-import { IconAdjustments, IconFlag, IconPlayerPlay, IconRefresh, IconTool, IconTrash } from "@tabler/icons";
+import {
+  IconAdjustments,
+  IconFileExport,
+  IconFileImport,
+  IconFlag,
+  IconPlayerPlay,
+  IconRefresh,
+  IconTool,
+  IconTrash,
+} from "@tabler/icons";
+import { open, save } from "@tauri-apps/api/dialog";
 import { useState } from "react";
 import { ConsumerOffsetConfiguration, ConsumerGroupInfo } from "../../models";
-import { deleteConsumerGroup, setConsumerGroup } from "../../tauri/admin";
+import { OffsetsFileFormat } from "../../models/kafka";
+import {
+  deleteConsumerGroup,
+  exportConsumerGroupOffsets,
+  importConsumerGroupOffsets,
+  setConsumerGroup,
+} from "../../tauri/admin";
 import { UpsertConsumerGroupModal } from "./upsert-consumer-group-modal";
 
 type ToolsMenuProps = {
@@ -50,6 +66,66 @@ export const ToolsMenu = (props: ToolsMenuProps) => {
     });
   };
 
+  const offsetsFileFilters = [
+    { name: "JSON", extensions: ["json"] },
+    { name: "CSV", extensions: ["csv"] },
+  ];
+  const getOffsetsFileFormat = (path: string): OffsetsFileFormat =>
+    path.toLowerCase().endsWith(".csv") ? "Csv" : "Json";
+
+  const exportOffsets = async () => {
+    const outputPath = await save({ defaultPath: `${data.name}-offsets.json`, filters: offsetsFileFilters });
+    if (outputPath) {
+      await exportConsumerGroupOffsets(clusterId, data.name, outputPath, getOffsetsFileFormat(outputPath));
+    }
+  };
+
+  const importOffsets = async () => {
+    const inputPath = (await open({ directory: false, multiple: false, filters: offsetsFileFilters })) as string | null;
+    if (!inputPath) return;
+    const format = getOffsetsFileFormat(inputPath);
+    const diff = await importConsumerGroupOffsets(clusterId, data.name, inputPath, format, true);
+    openConfirmModal({
+      title: "Import consumer group offsets",
+      size: 700,
+      children: (
+        <>
+          <Text size="sm">{`The following offsets will be committed to the consumer group ${data.name}`}</Text>
+          <ScrollArea.Autosize maxHeight={400} my={10}>
+            <Table>
+              <thead>
+                <tr>
+                  <th>Topic</th>
+                  <th>Partition</th>
+                  <th>Current offset</th>
+                  <th>New offset</th>
+                </tr>
+              </thead>
+              <tbody>
+                {diff.map((r) => (
+                  <tr key={`${r.topic}-${r.partitionId}`}>
+                    <td>{r.topic}</td>
+                    <td>{r.partitionId}</td>
+                    <td>{r.currentOffset ?? "-"}</td>
+                    <td>{r.newOffset}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </Table>
+          </ScrollArea.Autosize>
+          <Text size="sm" color={"red"}>
+            This action is irreversible.
+          </Text>
+        </>
+      ),
+      labels: { confirm: "Confirm", cancel: "Cancel" },
+      onConfirm: async () => {
+        await importConsumerGroupOffsets(clusterId, data.name, inputPath, format, false);
+        onRefresh();
+      },
+    });
+  };
+
   const openDeleteGroupModal = () =>
     openConfirmModal({
       title: "Are you sure to delete this consumer group?",
@@ -86,6 +162,9 @@ export const ToolsMenu = (props: ToolsMenuProps) => {
           <Menu.Item icon={<IconRefresh size={14} />} onClick={() => onRefresh()} disabled={loading || disabled}>
             Refresh
           </Menu.Item>
+          <Menu.Item icon={<IconFileExport size={14} />} onClick={exportOffsets} disabled={loading || disabled}>
+            Export offsets
+          </Menu.Item>
           <Menu.Label>Reset offset</Menu.Label>
           <Menu.Item
             color={"orange"}
@@ -99,6 +178,9 @@ export const ToolsMenu = (props: ToolsMenuProps) => {
           <Menu.Item color={"orange"} onClick={() => openCustomOffsetModal()} icon={<IconAdjustments size={14} />}>
             Custom
           </Menu.Item>
+          <Menu.Item color={"orange"} onClick={importOffsets} icon={<IconFileImport size={14} />}>
+            Import from file
+          </Menu.Item>
           <Menu.Label>Danger zone</Menu.Label>
           <Menu.Item color={"red"} icon={<IconTrash size={14} />} onClick={() => openDeleteGroupModal()}>
             Delete
@@ -1,10 +1,11 @@
-import { Autocomplete, Button, Group, Input, Stack } from "@mantine/core";
+import { Alert, Autocomplete, Button, Chip, Group, Input, List, Stack } from "@mantine/core";
 import { useForm } from "@mantine/form";
 import { useState } from "react";
 import { CodeEditor, ResizableModal } from "../../components";
-import { postSchema } from "../../tauri/schema-registry";
+import { CompatibilityCheck } from "../../models";
+import { checkSchemaCompatibility, postSchema } from "../../tauri/schema-registry";
 
-type FormType = { subjectName: string; avroSchema: string };
+type FormType = { subjectName: string; avroSchema: string; schemaType: "AVRO" | "JSON" };
 
 type AddSchemaModalProps = {
   subjects: string[];
@@ -14,11 +15,16 @@ type AddSchemaModalProps = {
 };
 
 export const AddSchemaModal = ({ subjects, clusterId, opened, onClose }: AddSchemaModalProps) => {
-  const [state, setState] = useState<{ isUploading: boolean }>({ isUploading: false });
+  const [state, setState] = useState<{ isUploading: boolean; isChecking: boolean }>({
+    isUploading: false,
+    isChecking: false,
+  });
+  const [compatibility, setCompatibility] = useState<CompatibilityCheck | undefined>(undefined);
   const schemaNameRegex = /^[a-zA-Z0-9._-]+$/g;
   const form = useForm<FormType>({
     initialValues: {
       subjectName: "",
+      schemaType: "AVRO",
       avroSchema: `{
     "type" : "record",
     "name" : "Example",
@@ -42,11 +48,21 @@ export const AddSchemaModal = ({ subjects, clusterId, opened, onClose }: AddSche
     },
   });
   const onSubmit = async (v: FormType) => {
-    setState({ isUploading: true });
+    setState((s) => ({ ...s, isUploading: true }));
     try {
-      await postSchema(clusterId, v.subjectName, v.avroSchema).then((_) => onClose());
+      await postSchema(clusterId, v.subjectName, v.avroSchema, v.schemaType).then((_) => onClose());
     } finally {
-      setState({ isUploading: false });
+      setState((s) => ({ ...s, isUploading: false }));
+    }
+  };
+  const onCheckCompatibility = async () => {
+    if (form.validate().hasErrors) return;
+    const { subjectName, avroSchema, schemaType } = form.values;
+    setState((s) => ({ ...s, isChecking: true }));
+    try {
+      setCompatibility(await checkSchemaCompatibility(clusterId, subjectName, avroSchema, schemaType));
+    } finally {
+      setState((s) => ({ ...s, isChecking: false }));
     }
   };
 
@@ -63,14 +79,41 @@ export const AddSchemaModal = ({ subjects, clusterId, opened, onClose }: AddSche
             data={subjects}
             {...form.getInputProps("subjectName")}
           />
+          <Input.Wrapper label="Schema type">
+            <Chip.Group position="left" multiple={false} {...form.getInputProps("schemaType")}>
+              <Chip value="AVRO">Avro</Chip>
+              <Chip value="JSON">JSON Schema</Chip>
+            </Chip.Group>
+          </Input.Wrapper>
           <Input.Wrapper
             style={{ height: "calc(100% - 100px)" }}
             id="schema-input"
-            label="Avro schema"
+            label={form.values.schemaType == "AVRO" ? "Avro schema" : "JSON schema"}
             error={form.getInputProps("avroSchema").error}>
             <CodeEditor {...form.getInputProps("avroSchema")} language="json" height="calc(100% - 30px)" />
           </Input.Wrapper>
+          {compatibility && (
+            <Alert
+              color={compatibility.isCompatible ? "green" : "red"}
+              title={compatibility.isCompatible ? "The schema is compatible" : "The schema is not compatible"}
+              withCloseButton
+              onClose={() => setCompatibility(undefined)}>
+              <List size="sm">
+                {compatibility.messages.map((m, i) => (
+                  <List.Item key={i}>{m}</List.Item>
+                ))}
+              </List>
+            </Alert>
+          )}
           <Group mt={"1em"} position="right">
+            <Button
+              variant="default"
+              loading={state.isChecking}
+              disabled={!subjects.includes(form.values.subjectName)}
+              onClick={onCheckCompatibility}
+              size="sm">
+              Check compatibility
+            </Button>
             <Button loading={state.isUploading} type="submit" size="sm">
               Validate and submit
             </Button>
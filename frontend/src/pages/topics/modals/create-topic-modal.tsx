@@ -1,5 +1,5 @@
 import { createTopic } from "../../../tauri/admin";
-import { Button, Checkbox, Group, NumberInput, Stack, TextInput } from "@mantine/core";
+import { Button, Checkbox, Group, NumberInput, Stack, Textarea, TextInput } from "@mantine/core";
 import { useForm } from "@mantine/form";
 
 type CreateTopicForm = {
@@ -7,8 +7,22 @@ type CreateTopicForm = {
   partitions: number;
   isr: number;
   compacted: boolean;
+  /** one `key=value` entry per line */
+  configurations: string;
 };
 
+const parseConfigurations = (v: string): Record<string, string> =>
+  Object.fromEntries(
+    v
+      .split("\n")
+      .map((line) => line.trim())
+      .filter((line) => line.length > 0)
+      .map((line) => {
+        const separator = line.indexOf("=");
+        return [line.slice(0, separator).trim(), line.slice(separator + 1).trim()];
+      }),
+  );
+
 type CreateTopicModalProps = {
   clusterId: string;
   onClose: () => void;
@@ -21,6 +35,7 @@ export const CreateTopicModal = ({ clusterId, onClose }: CreateTopicModalProps)
       compacted: false,
       partitions: 3,
       isr: 1,
+      configurations: "",
     },
     validate: {
       name: (v) => {
@@ -30,12 +45,29 @@ export const CreateTopicModal = ({ clusterId, onClose }: CreateTopicModalProps)
       },
       partitions: (v) => (v > 0 ? null : "Number of partitions must be greater than 0"),
       isr: (v) => (v > 0 ? null : "Number of in sync replicas must be greater than 0"),
+      configurations: (v) =>
+        v
+          .split("\n")
+          .map((line) => line.trim())
+          .every((line) => line.length == 0 || line.indexOf("=") > 0)
+          ? null
+          : "Each configuration must be in the format key=value",
     },
   });
   const onSubmit = async (v: CreateTopicForm) => {
-    await createTopic(clusterId, v.name, v.partitions, v.isr, v.compacted);
+    await createTopic(clusterId, v.name, v.partitions, v.isr, v.compacted, {
+      configurations: parseConfigurations(v.configurations),
+    });
     onClose();
   };
+  const onValidate = async () => {
+    if (form.validate().hasErrors) return;
+    const v = form.values;
+    await createTopic(clusterId, v.name, v.partitions, v.isr, v.compacted, {
+      configurations: parseConfigurations(v.configurations),
+      validateOnly: true,
+    });
+  };
   return (
     <form onSubmit={form.onSubmit(onSubmit)}>
       <Stack>
@@ -49,7 +81,17 @@ export const CreateTopicModal = ({ clusterId, onClose }: CreateTopicModalProps)
         <NumberInput min={1} label="Partitions" {...form.getInputProps("partitions")} />
         <NumberInput min={1} label="In sync replicas" {...form.getInputProps("isr")} />
         <Checkbox label="Compacted" {...form.getInputProps("compacted", { type: "checkbox" })} />
+        <Textarea
+          label="Configurations"
+          description="One key=value entry per line, e.g. retention.ms=3600000"
+          autosize
+          minRows={2}
+          {...form.getInputProps("configurations")}
+        />
         <Group mt={10} position="right">
+          <Button variant="outline" onClick={onValidate}>
+            Validate
+          </Button>
           <Button type="submit">Create</Button>
         </Group>
       </Stack>
@@ -4,7 +4,13 @@ import { useState } from "react";
 import { CodeEditor, ResizableModal } from "../../../components";
 import { produceRecord } from "../../../tauri/producer";
 
-type FormType = { key: string; value: string; tombstone: boolean; mode: "Avro" | "String" };
+type FormType = {
+  key: string;
+  value: string;
+  tombstone: boolean;
+  mode: "Avro" | "Protobuf" | "Json" | "String";
+  recordName: string;
+};
 
 type AddSchemaModalProps = {
   topic: string;
@@ -21,6 +27,7 @@ export const ProducerModal = ({ topic, clusterId, opened, onClose }: AddSchemaMo
       value: "",
       mode: "Avro",
       tombstone: false,
+      recordName: "",
     },
     validate: {
       key: (v) => (v === null || v.length === 0 ? "Record key must be non empty" : null),
@@ -30,7 +37,9 @@ export const ProducerModal = ({ topic, clusterId, opened, onClose }: AddSchemaMo
   const onSubmit = async (v: FormType) => {
     setState({ isProducing: true });
     try {
-      await produceRecord(clusterId, topic, v.key, v.tombstone ? null : v.value, v.mode);
+      await produceRecord(clusterId, topic, v.key, v.tombstone ? null : v.value, v.mode, {
+        recordName: v.recordName.length > 0 ? v.recordName : undefined,
+      });
       onClose();
     } finally {
       setState({ isProducing: false });
@@ -58,6 +67,8 @@ export const ProducerModal = ({ topic, clusterId, opened, onClose }: AddSchemaMo
             <Input.Wrapper label="Serialization">
               <Chip.Group position="left" multiple={false} {...form.getInputProps("mode")}>
                 <Chip value="Avro">Avro</Chip>
+                <Chip value="Protobuf">Protobuf</Chip>
+                <Chip value="Json">JSON Schema</Chip>
                 <Chip value="String">String</Chip>
               </Chip.Group>
             </Input.Wrapper>
@@ -68,6 +79,15 @@ export const ProducerModal = ({ topic, clusterId, opened, onClose }: AddSchemaMo
               />
             </Input.Wrapper>
           </Group>
+          <TextInput
+            hidden={form.values.mode == "String" || form.values.tombstone}
+            label="Record name"
+            description="Fully qualified name of the record type. Required by the record name subject strategies"
+            autoComplete="off"
+            autoCorrect="off"
+            autoCapitalize="off"
+            {...form.getInputProps("recordName")}
+          />
           <Input.Wrapper
             hidden={form.values.tombstone}
             style={{ height: "calc(100% - 100px)" }}
@@ -80,8 +100,8 @@ export const ProducerModal = ({ topic, clusterId, opened, onClose }: AddSchemaMo
               {...form.getInputProps("value")}
             />
           </Input.Wrapper>
-          <Text hidden={form.values.mode == "Avro"} color={"red"}>
-            The producer will create a string record. Make sure downstream consumer do not expect Avro before producing.
+          <Text hidden={form.values.mode != "String"} color={"red"}>
+            The producer will create a string record. Make sure downstream consumer do not expect a schema before producing.
           </Text>
           <Group position="right">
             <Button loading={state.isProducing} type="submit" size="sm">
@@ -8,7 +8,9 @@ describe("topicInfoModal", () => {
     const testTopicInfo: TopicInfo = {
       configurations: { ["sample.ms"]: "123", ["sample.bytes"]: "1233", sampe: "321" },
       name: "Topic name",
-      partitions: [],
+      partitions: [
+        { id: 0, isr: 1, replicas: 2, last_offset: 0, leader: 1, replicaIds: [1, 2], isrIds: [1], underReplicated: true },
+      ],
     };
     render(<TopicInfoModal topicInfo={testTopicInfo} />);
   });
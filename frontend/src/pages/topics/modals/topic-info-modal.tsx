@@ -1,4 +1,4 @@
-import { TextInput, Stack, Table, Input, Group } from "@mantine/core";
+import { TextInput, Stack, Table, Input, Group, Text } from "@mantine/core";
 import { parseBytesToHumanReadable, parseMsToHumanReadable } from "../../../helpers/human-readable";
 import { TopicInfo } from "../../../models";
 
@@ -18,13 +18,44 @@ export const TopicInfoModal = ({ topicInfo }: { topicInfo: TopicInfo }) => {
       </tr>
     ));
 
+  const partitionRows = topicInfo.partitions.map((p) => (
+    <tr key={p.id}>
+      <td>{p.id}</td>
+      <td>{p.leader ?? <Text color="red">offline</Text>}</td>
+      <td>{p.replicaIds.join(", ")}</td>
+      <td>
+        <Text color={p.underReplicated ? "red" : undefined}>{p.isrIds.join(", ")}</Text>
+      </td>
+    </tr>
+  ));
+  const underReplicatedCount = topicInfo.partitions.filter((p) => p.underReplicated).length;
+
   return (
     <Stack spacing={5}>
       <TextInput readOnly label="Topic name" value={topicInfo.name} />
       <Group position="apart" grow>
         <TextInput readOnly label="Partitions Count" value={topicInfo.partitions.length} />
         <TextInput readOnly label="ISR" value={topicInfo.partitions.map((p) => p.isr).join(", ")} />
+        <TextInput
+          readOnly
+          label="Under replicated partitions"
+          value={underReplicatedCount}
+          error={underReplicatedCount > 0}
+        />
       </Group>
+      <Input.Wrapper mt={10} label="Partitions">
+        <Table>
+          <thead>
+            <tr>
+              <th>Partition</th>
+              <th>Leader</th>
+              <th>Replicas</th>
+              <th>In sync replicas</th>
+            </tr>
+          </thead>
+          <tbody>{partitionRows}</tbody>
+        </Table>
+      </Input.Wrapper>
       <Input.Wrapper mt={10} label="Topic configurations">
         <Table>
           <tbody>{rows}</tbody>
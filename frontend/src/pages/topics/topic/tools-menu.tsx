@@ -1,6 +1,6 @@
 import { ActionIcon, Text, Menu, Title } from "@mantine/core";
-import { IconFileExport, IconInfoCircle, IconSatellite, IconTool, IconTrash } from "@tabler/icons";
-import { deleteTopic, getTopicInfo } from "../../../tauri/admin";
+import { IconEraser, IconFileExport, IconInfoCircle, IconSatellite, IconTool, IconTrash } from "@tabler/icons";
+import { deleteRecords, deleteTopic, getTopicInfo, previewDeleteRecords } from "../../../tauri/admin";
 import { openConfirmModal, openModal } from "@mantine/modals";
 import { TopicInfoModal } from "../modals/topic-info-modal";
 import { useWindowHandler } from "../../../components";
@@ -34,6 +34,27 @@ export const ToolsMenu = (props: ToolsMenuProps) => {
       },
     });
 
+  const openTruncateTopicModal = async () => {
+    const preview = await previewDeleteRecords(clusterId, topic, "All");
+    const recordsCount = preview.reduce((acc, p) => acc + p.recordsCount, 0);
+    openConfirmModal({
+      title: "Are you sure to truncate this topic?",
+      children: (
+        <>
+          <Text color="red" size="sm">
+            Up to {recordsCount} records will be deleted from the {preview.length} partitions of topic {topic}. This
+            action is not reversible!
+          </Text>
+          <Text size="sm">Note: this operation may fail if the ACLs do not allow the deletion.</Text>
+        </>
+      ),
+      labels: { confirm: "Confirm", cancel: "Cancel" },
+      onConfirm: async () => {
+        await deleteRecords(clusterId, topic, "All");
+      },
+    });
+  };
+
   const openInfoModal = async () => {
     const topicInfo = await getTopicInfo(clusterId, topic);
     return openModal({
@@ -66,6 +87,9 @@ export const ToolsMenu = (props: ToolsMenuProps) => {
         <Menu.Item icon={<IconInfoCircle size={14} />} onClick={openInfoModal}>
           Topic info
         </Menu.Item>
+        <Menu.Item color="red" icon={<IconEraser size={14} />} onClick={openTruncateTopicModal}>
+          Truncate topic
+        </Menu.Item>
         <Menu.Item color="red" icon={<IconTrash size={14} />} onClick={openDeleteTopicModal}>
           Delete topic
         </Menu.Item>
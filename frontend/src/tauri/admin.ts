@@ -1,8 +1,31 @@
 import { invoke } from "@tauri-apps/api";
-import { ConsumerGroupInfo, ConsumerOffsetConfiguration, PartitionOffset, TopicInfo } from "../models/kafka";
+import {
+  AclBinding,
+  AclBindingFilter,
+  ClusterInfo,
+  ConsumerGroupInfo,
+  ConsumerGroupLag,
+  ConsumerGroupOffsets,
+  ConsumerOffsetConfiguration,
+  DeletedRecords,
+  DeleteRecordsConfiguration,
+  OffsetReset,
+  OffsetResetStrategy,
+  OffsetsFileFormat,
+  PartitionOffset,
+  TopicInfo,
+} from "../models/kafka";
 import { withNotifications } from "./error";
 
-export const setConsumerGroup = (
+export const describeCluster = (clusterId: string): Promise<ClusterInfo> =>
+  withNotifications({ action: () => invoke<ClusterInfo>("describe_cluster", { clusterId }) });
+
+export const getBrokerConfiguration = (clusterId: string, brokerId: number): Promise<Record<string, string | null>> =>
+  withNotifications({
+    action: () => invoke<Record<string, string | null>>("get_broker_configuration", { clusterId, brokerId }),
+  });
+
+
   clusterId: string,
   consumerGroupName: string,
   topics: string[],
@@ -13,6 +36,66 @@ export const setConsumerGroup = (
     successTitle: `Consumer group ${consumerGroupName} updated`,
   });
 
+/** Reset the offsets of the consumer group on the topic. With dryRun the new offsets are not committed */
+export const resetConsumerGroupOffsets = (
+  clusterId: string,
+  consumerGroupName: string,
+  topicName: string,
+  strategy: OffsetResetStrategy,
+  partitions: number[] | null,
+  dryRun: boolean,
+): Promise<OffsetReset[]> =>
+  withNotifications({
+    action: () =>
+      invoke<OffsetReset[]>("reset_consumer_group_offsets", {
+        clusterId,
+        consumerGroupName,
+        topicName,
+        strategy,
+        partitions,
+        dryRun,
+      }),
+    successTitle: dryRun ? undefined : `Consumer group ${consumerGroupName} updated`,
+  });
+
+export const exportConsumerGroupOffsets = (
+  clusterId: string,
+  consumerGroupName: string,
+  outputPath: string,
+  format: OffsetsFileFormat,
+): Promise<ConsumerGroupOffsets> =>
+  withNotifications({
+    action: () =>
+      invoke<ConsumerGroupOffsets>("export_consumer_group_offsets", {
+        clusterId,
+        consumerGroupName,
+        outputPath,
+        format,
+        overwrite: true,
+      }),
+    successTitle: `Offsets of ${consumerGroupName} exported to ${outputPath}`,
+  });
+
+/** Restore the offsets from file. With dryRun the difference with the current offsets is not committed */
+export const importConsumerGroupOffsets = (
+  clusterId: string,
+  consumerGroupName: string,
+  inputPath: string,
+  format: OffsetsFileFormat,
+  dryRun: boolean,
+): Promise<OffsetReset[]> =>
+  withNotifications({
+    action: () =>
+      invoke<OffsetReset[]>("import_consumer_group_offsets", {
+        clusterId,
+        consumerGroupName,
+        inputPath,
+        format,
+        dryRun,
+      }),
+    successTitle: dryRun ? undefined : `Offsets imported into ${consumerGroupName}`,
+  });
+
 export const getConsumerGroupState = (clusterId: string, consumerGroupName: string): Promise<string> =>
   withNotifications({ action: () => invoke<string>("get_consumer_group_state", { clusterId, consumerGroupName }) });
 
@@ -25,22 +108,75 @@ export const describeConsumerGroup = (
     action: () => invoke<ConsumerGroupInfo>("describe_consumer_group", { clusterId, consumerGroupName, ignoreCache }),
   });
 
+export const getConsumerGroupLag = (
+  clusterId: string,
+  consumerGroupName: string,
+  ignoreCache: boolean,
+): Promise<ConsumerGroupLag> =>
+  withNotifications({
+    action: () => invoke<ConsumerGroupLag>("get_consumer_group_lag", { clusterId, consumerGroupName, ignoreCache }),
+  });
+
 export const getConsumerGroups = (clusterId: string): Promise<string[]> =>
   withNotifications({
     action: () => invoke<string[]>("list_consumer_groups", { clusterId }),
     successTitle: "List of consumer groups loaded",
   });
 
+export type CreateTopicOptions = {
+  /** topic configuration entries, cleanup.policy takes precedence over the compacted flag */
+  configurations?: Record<string, string>;
+  /** validate the request without creating the topic */
+  validateOnly?: boolean;
+};
+
 export const createTopic = (
   clusterId: string,
   topicName: string,
   partitions: number,
   isr: number,
   compacted: boolean,
+  options?: CreateTopicOptions,
+): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("create_topic", { clusterId, topicName, partitions, isr, compacted, options }),
+    successTitle: options?.validateOnly ? `Topic ${topicName} configuration is valid` : `Topic ${topicName} created`,
+  });
+
+/** a null value resets the configuration to the default */
+export const alterTopicConfiguration = (
+  clusterId: string,
+  topicName: string,
+  configurations: Record<string, string | null>,
 ): Promise<void> =>
   withNotifications({
-    action: () => invoke<void>("create_topic", { clusterId, topicName, partitions, isr, compacted }),
-    successTitle: `Topic ${topicName} created`,
+    action: () => invoke<void>("alter_topic_configuration", { clusterId, topicName, configurations }),
+    successTitle: `Configuration of topic ${topicName} updated`,
+  });
+
+export const createPartitions = (clusterId: string, topicName: string, partitionCount: number): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("create_partitions", { clusterId, topicName, partitionCount }),
+    successTitle: `Topic ${topicName} now has ${partitionCount} partitions`,
+  });
+
+export const previewDeleteRecords = (
+  clusterId: string,
+  topicName: string,
+  configuration: DeleteRecordsConfiguration,
+): Promise<DeletedRecords[]> =>
+  withNotifications({
+    action: () => invoke<DeletedRecords[]>("preview_delete_records", { clusterId, topicName, configuration }),
+  });
+
+export const deleteRecords = (
+  clusterId: string,
+  topicName: string,
+  configuration: DeleteRecordsConfiguration,
+): Promise<DeletedRecords[]> =>
+  withNotifications({
+    action: () => invoke<DeletedRecords[]>("delete_records", { clusterId, topicName, configuration }),
+    successTitle: `Records deleted from topic ${topicName}`,
   });
 
 export const listTopics = (clusterId: string): Promise<string[]> =>
@@ -70,3 +206,18 @@ export const deleteConsumerGroup = (clusterId: string, consumerGroupName: string
     successTitle: `Consumer group ${consumerGroupName} deleted`,
     showInModal: true,
   });
+
+export const describeAcls = (clusterId: string, filter?: AclBindingFilter): Promise<AclBinding[]> =>
+  withNotifications({ action: () => invoke<AclBinding[]>("describe_acls", { clusterId, filter }) });
+
+export const createAcls = (clusterId: string, acls: AclBinding[]): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("create_acls", { clusterId, acls }),
+    successTitle: `${acls.length} ACLs created`,
+  });
+
+export const deleteAcls = (clusterId: string, filters: AclBindingFilter[]): Promise<AclBinding[]> =>
+  withNotifications({
+    action: () => invoke<AclBinding[]>("delete_acls", { clusterId, filters }),
+    successTitle: "ACLs deleted",
+  });
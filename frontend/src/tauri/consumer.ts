@@ -56,3 +56,20 @@ export const exportRecords = (clusterId: string, topic: string, options: ExportO
       }),
     showInModal: true,
   });
+
+export type StoredTopic = {
+  topicName: string;
+  recordsCount: number;
+};
+
+export const listStoredTopics = (clusterId: string): Promise<StoredTopic[]> =>
+  withNotifications({
+    action: () => invoke<StoredTopic[]>("list_stored_topics", { clusterId }),
+  });
+
+export const deleteStoredTopic = (clusterId: string, topic: string): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("delete_stored_topic", { clusterId, topic }),
+    successTitle: `Records stored for ${topic} deleted`,
+    showInModal: true,
+  });
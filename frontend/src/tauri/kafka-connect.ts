@@ -0,0 +1,59 @@
+import { invoke } from "@tauri-apps/api";
+import { ConnectorStatus } from "../models";
+import { withNotifications } from "./error";
+
+export const listConnectors = (clusterId: string): Promise<ConnectorStatus[]> =>
+  withNotifications({ action: () => invoke<ConnectorStatus[]>("list_connectors", { clusterId }) });
+
+export const getConnectorStatus = (clusterId: string, name: string): Promise<ConnectorStatus> =>
+  withNotifications({ action: () => invoke<ConnectorStatus>("get_connector_status", { clusterId, name }) });
+
+export const getConnectorConfig = (clusterId: string, name: string): Promise<Record<string, string>> =>
+  withNotifications({ action: () => invoke<Record<string, string>>("get_connector_config", { clusterId, name }) });
+
+export const updateConnectorConfig = (
+  clusterId: string,
+  name: string,
+  config: Record<string, string>,
+): Promise<Record<string, string>> =>
+  withNotifications({
+    action: () => invoke<Record<string, string>>("update_connector_config", { clusterId, name, config }),
+    successTitle: `Configuration of connector ${name} updated`,
+    showInModal: true,
+  });
+
+export const pauseConnector = (clusterId: string, name: string): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("pause_connector", { clusterId, name }),
+    successTitle: `Connector ${name} paused`,
+  });
+
+export const resumeConnector = (clusterId: string, name: string): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("resume_connector", { clusterId, name }),
+    successTitle: `Connector ${name} resumed`,
+  });
+
+export const restartConnector = (
+  clusterId: string,
+  name: string,
+  includeTasks = false,
+  onlyFailed = false,
+): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("restart_connector", { clusterId, name, includeTasks, onlyFailed }),
+    successTitle: `Connector ${name} restarted`,
+  });
+
+export const restartConnectorTask = (clusterId: string, name: string, taskId: number): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("restart_connector_task", { clusterId, name, taskId }),
+    successTitle: `Task ${taskId} of connector ${name} restarted`,
+  });
+
+export const deleteConnector = (clusterId: string, name: string): Promise<void> =>
+  withNotifications({
+    action: () => invoke<void>("delete_connector", { clusterId, name }),
+    successTitle: `Connector ${name} deleted`,
+    showInModal: true,
+  });
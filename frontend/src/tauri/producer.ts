@@ -1,15 +1,35 @@
 import { invoke } from "@tauri-apps/api";
+import { ParserMode } from "../models";
 import { withNotifications } from "./error";
 
+export type ProduceOptions = {
+  headers?: [string, string | null][];
+  partition?: number;
+  /** unix timestamp in ms */
+  timestamp?: number;
+  /** serialization of the key, String if not set */
+  keyMode?: ParserMode;
+  /** fully qualified name of the value record type, required by the record name subject strategies */
+  recordName?: string;
+  /** fully qualified name of the key record type, required by the record name subject strategies */
+  keyRecordName?: string;
+};
+
+export type DeliveryReport = {
+  partition: number;
+  offset: number;
+};
+
 export const produceRecord = (
   clusterId: string,
   topic: string,
   key: string,
   value: string | null,
-  mode: "Avro" | "String",
-): Promise<void> =>
+  mode: ParserMode,
+  options?: ProduceOptions,
+): Promise<DeliveryReport> =>
   withNotifications({
-    action: () => invoke<void>("produce_record", { clusterId, topic, key, value, mode }),
+    action: () => invoke<DeliveryReport>("produce_record", { clusterId, topic, key, value, mode, options }),
     successTitle: `Record with key ${key} produced to topic ${topic}`,
     showInModal: true,
   });
@@ -1,5 +1,5 @@
 import { invoke } from "@tauri-apps/api";
-import { Subject } from "../models";
+import { CompatibilityCheck, SchemaChange, SchemaReference, SchemaRegistryMode, Subject } from "../models";
 import { withNotifications } from "./error";
 
 export const listSubjects = (clusterId: string): Promise<string[]> =>
@@ -11,29 +11,107 @@ export const listSubjects = (clusterId: string): Promise<string[]> =>
 export const getSubject = (clusterId: string, subjectName: string): Promise<Subject> =>
   withNotifications({ action: () => invoke<Subject>("get_subject", { clusterId, subjectName }) });
 
-export const deleteSubject = (clusterId: string, subjectName: string): Promise<void> =>
+export const listDeletedSubjects = (clusterId: string): Promise<string[]> =>
+  withNotifications({ action: () => invoke<string[]>("list_deleted_subjects", { clusterId }) });
+
+export const listDeletedSubjectVersions = (clusterId: string, subjectName: string): Promise<number[]> =>
+  withNotifications({ action: () => invoke<number[]>("list_deleted_subject_versions", { clusterId, subjectName }) });
+
+/** the delete is soft unless permanent is set */
+export const deleteSubject = (clusterId: string, subjectName: string, permanent = false): Promise<void> =>
   withNotifications({
-    action: () => invoke<void>("delete_subject", { clusterId, subjectName }),
-    successTitle: `Subject ${subjectName} deleted`,
+    action: () => invoke<void>("delete_subject", { clusterId, subjectName, permanent }),
+    successTitle: `Subject ${subjectName} ${permanent ? "permanently " : ""}deleted`,
     showInModal: true,
   });
 
-export const deleteSubjectVersion = (clusterId: string, subjectName: string, version: number): Promise<void> =>
+/** the delete is soft unless permanent is set */
+export const deleteSubjectVersion = (
+  clusterId: string,
+  subjectName: string,
+  version: number,
+  permanent = false,
+): Promise<void> =>
   withNotifications({
-    action: () => invoke<void>("delete_subject_version", { clusterId, subjectName, version }),
-    successTitle: `Version ${version} of subject ${subjectName} deleted`,
+    action: () => invoke<void>("delete_subject_version", { clusterId, subjectName, version, permanent }),
+    successTitle: `Version ${version} of subject ${subjectName} ${permanent ? "permanently " : ""}deleted`,
     showInModal: true,
   });
 
-export const postSchema = (clusterId: string, subjectName: string, schema: string): Promise<void> =>
+export const postSchema = (
+  clusterId: string,
+  subjectName: string,
+  schema: string,
+  schemaType: "AVRO" | "PROTOBUF" | "JSON" = "AVRO",
+  references: SchemaReference[] = [],
+): Promise<void> =>
   withNotifications({
-    action: () => invoke<void>("post_schema", { clusterId, subjectName, schema }),
+    action: () => invoke<void>("post_schema", { clusterId, subjectName, schema, schemaType, references }),
     successTitle: `New version for ${subjectName} created`,
   });
 
+/** check the schema against a version of the subject, the latest if not specified, without registering it */
+export const checkSchemaCompatibility = (
+  clusterId: string,
+  subjectName: string,
+  schema: string,
+  schemaType: "AVRO" | "PROTOBUF" | "JSON" = "AVRO",
+  references: SchemaReference[] = [],
+  version?: number,
+): Promise<CompatibilityCheck> =>
+  withNotifications({
+    action: () =>
+      invoke<CompatibilityCheck>("check_schema_compatibility", {
+        clusterId,
+        subjectName,
+        schema,
+        schemaType,
+        references,
+        version,
+      }),
+  });
+
+export const diffSchemaVersions = (
+  clusterId: string,
+  subjectName: string,
+  fromVersion: number,
+  toVersion: number,
+): Promise<SchemaChange[]> =>
+  withNotifications({
+    action: () => invoke<SchemaChange[]>("diff_schema_versions", { clusterId, subjectName, fromVersion, toVersion }),
+  });
+
 export const setCompatibilityLevel = (clusterId: string, subjectName: string, compatibility: string): Promise<void> =>
   withNotifications({
     action: () => invoke<void>("set_compatibility_level", { clusterId, subjectName, compatibility }),
     successTitle: `Compatibility update to ${compatibility} for subject ${subjectName}`,
     showInModal: true,
   });
+
+export const getGlobalCompatibilityLevel = (clusterId: string): Promise<string> =>
+  withNotifications({ action: () => invoke<string>("get_global_compatibility_level", { clusterId }) });
+
+export const setGlobalCompatibilityLevel = (clusterId: string, compatibility: string): Promise<string> =>
+  withNotifications({
+    action: () => invoke<string>("set_global_compatibility_level", { clusterId, compatibility }),
+    successTitle: `Global compatibility update to ${compatibility}`,
+    showInModal: true,
+  });
+
+/** mode of the subject, the global one if the subject is not specified */
+export const getSchemaRegistryMode = (clusterId: string, subjectName?: string): Promise<SchemaRegistryMode> =>
+  withNotifications({
+    action: () => invoke<SchemaRegistryMode>("get_schema_registry_mode", { clusterId, subjectName }),
+  });
+
+/** set the mode of the subject, the global one if the subject is not specified */
+export const setSchemaRegistryMode = (
+  clusterId: string,
+  mode: SchemaRegistryMode,
+  subjectName?: string,
+): Promise<SchemaRegistryMode> =>
+  withNotifications({
+    action: () => invoke<SchemaRegistryMode>("set_schema_registry_mode", { clusterId, subjectName, mode }),
+    successTitle: `Mode update to ${mode} for ${subjectName ?? "the schema registry"}`,
+    showInModal: true,
+  });